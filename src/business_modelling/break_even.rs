@@ -0,0 +1,174 @@
+use std::error::Error;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Product {
+    pub name: String,
+    pub price: f32,
+    pub variable_cost: f32,
+    // Relative units sold, e.g. 3 and 1 for three of this product to every one of another.
+    pub sales_mix: f32,
+}
+
+impl Product {
+    pub fn new(name: String, price: f32, variable_cost: f32, sales_mix: f32) -> Self {
+        Self {
+            name,
+            price,
+            variable_cost,
+            sales_mix,
+        }
+    }
+
+    // Per unit
+    pub fn get_contribution_margin(&self) -> f32 {
+        self.price - self.variable_cost
+    }
+
+    pub fn get_contribution_margin_ratio(&self) -> Option<f32> {
+        if self.price == 0.0 {
+            return None;
+        }
+
+        Some(self.get_contribution_margin() / self.price)
+    }
+}
+
+// Products sold in a constant sales mix sharing the same fixed costs. Unit figures are in units of all products
+// together, split by the sales mix.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SalesMix {
+    products: Vec<Product>,
+    fixed_costs: f32,
+}
+
+impl SalesMix {
+    pub fn new(products: Vec<Product>, fixed_costs: f32) -> Result<Self, Box<dyn Error>> {
+        if products.is_empty() {
+            Err("No products in the sales mix")?;
+        }
+        if products.iter().any(|product| product.sales_mix < 0.0) {
+            Err("Sales mix cannot be negative")?;
+        }
+        if products
+            .iter()
+            .fold(0.0, |acc, product| acc + product.sales_mix)
+            <= 0.0
+        {
+            Err("Sales mix must add up to more than zero")?;
+        }
+
+        Ok(Self {
+            products,
+            fixed_costs,
+        })
+    }
+
+    pub fn get_products(&self) -> &Vec<Product> {
+        &self.products
+    }
+
+    pub fn get_fixed_costs(&self) -> f32 {
+        self.fixed_costs
+    }
+
+    // Share of the total units sold of each product
+    pub fn get_sales_mix_shares(&self) -> Vec<(String, f32)> {
+        let total_mix = self.get_total_mix();
+
+        self.products
+            .iter()
+            .map(|product| (product.name.clone(), product.sales_mix / total_mix))
+            .collect()
+    }
+
+    // Contribution margin of an average unit of the mix
+    pub fn get_weighted_average_contribution_margin(&self) -> f32 {
+        self.products.iter().fold(0.0, |acc, product| {
+            acc + (product.get_contribution_margin() * product.sales_mix)
+        }) / self.get_total_mix()
+    }
+
+    // Contribution margin per unit of sales revenue of the mix
+    pub fn get_weighted_average_contribution_margin_ratio(&self) -> Option<f32> {
+        let revenue = self.get_mix_revenue();
+        if revenue == 0.0 {
+            return None;
+        }
+
+        Some(self.get_weighted_average_contribution_margin() * self.get_total_mix() / revenue)
+    }
+
+    // None when an average unit does not contribute anything towards the fixed costs
+    pub fn get_break_even_units(&self) -> Option<f32> {
+        let contribution_margin = self.get_weighted_average_contribution_margin();
+        if contribution_margin <= 0.0 {
+            return None;
+        }
+
+        Some(self.fixed_costs / contribution_margin)
+    }
+
+    pub fn get_break_even_units_by_product(&self) -> Option<Vec<(String, f32)>> {
+        let break_even_units = self.get_break_even_units()?;
+
+        Some(
+            self.get_sales_mix_shares()
+                .into_iter()
+                .map(|(name, share)| (name, break_even_units * share))
+                .collect(),
+        )
+    }
+
+    pub fn get_break_even_sales(&self) -> Option<f32> {
+        let contribution_margin_ratio = self.get_weighted_average_contribution_margin_ratio()?;
+        if contribution_margin_ratio <= 0.0 {
+            return None;
+        }
+
+        Some(self.fixed_costs / contribution_margin_ratio)
+    }
+
+    // Sales revenue above the break-even sales, negative below it
+    pub fn get_margin_of_safety(&self, sales: f32) -> Option<f32> {
+        Some(sales - self.get_break_even_sales()?)
+    }
+
+    pub fn get_margin_of_safety_ratio(&self, sales: f32) -> Option<f32> {
+        if sales == 0.0 {
+            return None;
+        }
+
+        Some(self.get_margin_of_safety(sales)? / sales)
+    }
+
+    pub fn get_sales(&self, units: f32) -> f32 {
+        self.get_mix_revenue() * units / self.get_total_mix()
+    }
+
+    pub fn get_operating_income(&self, units: f32) -> f32 {
+        (self.get_weighted_average_contribution_margin() * units) - self.fixed_costs
+    }
+
+    // Percent change in operating income per percent change in sales, None at break-even
+    pub fn get_degree_of_operating_leverage(&self, units: f32) -> Option<f32> {
+        let operating_income = self.get_operating_income(units);
+        if operating_income == 0.0 {
+            return None;
+        }
+
+        Some(self.get_weighted_average_contribution_margin() * units / operating_income)
+    }
+
+    // Revenue of selling every product in its sales_mix quantity
+    fn get_mix_revenue(&self) -> f32 {
+        self.products.iter().fold(0.0, |acc, product| {
+            acc + (product.price * product.sales_mix)
+        })
+    }
+
+    fn get_total_mix(&self) -> f32 {
+        self.products
+            .iter()
+            .fold(0.0, |acc, product| acc + product.sales_mix)
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+use crate::business_modelling::{assert_close, break_even::*};
+
+// Three A (contribution 4) sold for every B (contribution 8), fixed costs of 10,000
+#[cfg(test)]
+fn generate_sales_mix() -> SalesMix {
+    SalesMix::new(
+        vec![
+            Product::new("A".to_string(), 10.0, 6.0, 3.0),
+            Product::new("B".to_string(), 20.0, 12.0, 1.0),
+        ],
+        10000.0,
+    )
+    .unwrap()
+}
+
+#[test]
+fn it_computes_contribution_margins() {
+    let product = Product::new("A".to_string(), 10.0, 6.0, 3.0);
+    assert_eq!(product.get_contribution_margin(), 4.0);
+    assert_eq!(product.get_contribution_margin_ratio(), Some(0.4));
+
+    let sales_mix = generate_sales_mix();
+    assert_eq!(sales_mix.get_weighted_average_contribution_margin(), 5.0);
+    assert_close(
+        sales_mix
+            .get_weighted_average_contribution_margin_ratio()
+            .unwrap(),
+        0.4,
+    );
+}
+
+#[test]
+fn it_computes_weighted_average_break_even() {
+    let sales_mix = generate_sales_mix();
+
+    assert_eq!(sales_mix.get_break_even_units(), Some(2000.0));
+    assert_eq!(
+        sales_mix.get_break_even_units_by_product(),
+        Some(vec![("A".to_string(), 1500.0), ("B".to_string(), 500.0)])
+    );
+    assert_close(sales_mix.get_break_even_sales().unwrap(), 25000.0);
+    assert_close(sales_mix.get_operating_income(2000.0), 0.0);
+}
+
+#[test]
+fn it_computes_margin_of_safety_and_operating_leverage() {
+    let sales_mix = generate_sales_mix();
+    let sales = sales_mix.get_sales(3200.0);
+
+    assert_close(sales, 40000.0);
+    assert_close(sales_mix.get_margin_of_safety(sales).unwrap(), 15000.0);
+    assert_close(sales_mix.get_margin_of_safety_ratio(sales).unwrap(), 0.375);
+    assert_close(sales_mix.get_operating_income(3200.0), 6000.0);
+    // The inverse of the margin of safety ratio
+    assert_close(
+        sales_mix.get_degree_of_operating_leverage(3200.0).unwrap(),
+        1.0 / 0.375,
+    );
+    assert_eq!(sales_mix.get_degree_of_operating_leverage(2000.0), None);
+}
+
+#[test]
+fn it_returns_none_when_the_mix_does_not_cover_fixed_costs() {
+    let sales_mix = SalesMix::new(
+        vec![Product::new("A".to_string(), 10.0, 12.0, 1.0)],
+        10000.0,
+    )
+    .unwrap();
+
+    assert_eq!(sales_mix.get_break_even_units(), None);
+    assert_eq!(sales_mix.get_break_even_sales(), None);
+}
+
+#[test]
+fn it_returns_error_because_sales_mix_is_empty() {
+    let result = SalesMix::new(vec![], 10000.0);
+
+    match result {
+        Ok(_) => panic!("Expected an error"),
+        Err(err) => assert_eq!(err.to_string(), "No products in the sales mix"),
+    }
+}
@@ -1,9 +1,15 @@
-use std::{error::Error, fs, ops::Neg};
-
-use crate::business_modelling::linreg;
+use std::{error::Error, fs};
+
+use crate::business_modelling::{
+    cost_model::CostModel,
+    elasticity::{Elasticity, ElasticityClass},
+    equilibrium::{Market, MarketCondition},
+    intervention::Intervention,
+    linreg,
+};
 use chrono::Utc;
 use plotters::{
-    prelude::{BitMapBackend, ChartBuilder, Circle, IntoDrawingArea, PathElement},
+    prelude::{BitMapBackend, ChartBuilder, Circle, IntoDrawingArea, PathElement, Rectangle},
     series::LineSeries,
     style::{
         full_palette::{BROWN, ORANGE, PURPLE, RED_A700},
@@ -260,12 +266,11 @@ impl BusinessModelling {
                 let sc = Circle::new((price, supply_point), 5, RED.filled());
 
                 let circles = [dc, sc];
-                let relationship_label = if demand_point > supply_point {
-                    "Surplus"
-                } else if demand_point == supply_point {
-                    "Equilibrium"
-                } else {
-                    "Shortage"
+                let market = Market::new(demand_m, demand_b, supply_m, supply_b);
+                let relationship_label = match market.get_market_condition(price) {
+                    MarketCondition::Surplus(_) => "Surplus",
+                    MarketCondition::Equilibrium => "Equilibrium",
+                    MarketCondition::Shortage(_) => "Shortage",
                 };
 
                 scatterplot
@@ -294,148 +299,298 @@ impl BusinessModelling {
         return Ok(true);
     }
 
-    pub fn model(
+    pub fn demand_elasticity_scatterplot(
         prices: &Vec<f32>,
         quantity_purchase: &Vec<f32>,
-        fixed_cost: &f32,
-        manufacturing_cost: &f32,
+        shade_elasticity: bool,
         title: String,
         directory: Option<String>,
         height: Option<u32>,
         width: Option<u32>,
     ) -> Result<bool, Box<dyn Error>> {
-        // Get expense function by taking manufacturing cost per product and fixed cost (manufacuring_cost * quantity) + fixed_cost
+        let prices_len = prices.len();
+        let quantity_purchase_len = quantity_purchase.len();
 
-        // Get demand function constants (demand_m, demand_b)
-        let (demand_m, demand_b) = linreg::Linreg::linear_regress(&prices, &quantity_purchase)?;
+        if prices_len == 0 || quantity_purchase_len == 0 {
+            // Check if domain and range is not empty
+            Err("Insufficient lengths")?;
+        } else if prices_len != quantity_purchase_len {
+            // Check if domain and range has the same lengths
+            Err("Range length is not equal to domain length or vice versa")?;
+        }
 
-        // multiply em = manufacturing cost and demand_m, b = multiply demand_b and manufacturing cost
-        // em + ec (Expense function)
-        let em = manufacturing_cost * demand_m;
-        let eb = manufacturing_cost * demand_b;
-
-        // eb = add eb and fixed cost together
-        let eb = eb + fixed_cost;
-
-        // Get vertical axis intercept (Set this as end range for y (range))
-        let vertical_axis = (em * 0.0) + eb;
-
-        // Get horizontal axis intercept (Set this as end range for x (domain))
-        let horizontal_axis = eb / em.abs();
-
-        // Get revenue function by getting the demand function constants and justing making the given domain (price) squared
-
-        // Get max height / max price where revenue will be made. (-b / 2a)
-        let axis_of_symmetry_revenue_price = demand_b.neg() / (2.0 * demand_m);
-
-        let max_revenue = (demand_m * axis_of_symmetry_revenue_price.powi(2))
-            + (demand_b * axis_of_symmetry_revenue_price);
-
-        let max_revenue_point = [Circle::new(
-            (axis_of_symmetry_revenue_price, max_revenue),
-            5,
-            RED.filled(),
-        )];
-
-        /*** Get quadratic equation for breakeven ***/
-        let quadratic_eq_a = demand_m.abs();
-        let quadratic_eq_b = demand_b.neg() + em;
-        let quadratic_eq_c = &eb;
-
-        // Use quadratic formula to get two points of breakeven prices
-        let quadratic_formula_num_b = -1.0 * quadratic_eq_b;
-        let quadratic_formula_num_b_squared = quadratic_eq_b.powf(2.0);
-        let quadratic_formula_4ac = 4.0 * (quadratic_eq_a * quadratic_eq_c);
-        let quadratic_formula_numerator_minus = quadratic_formula_num_b
-            - (quadratic_formula_num_b_squared - quadratic_formula_4ac).sqrt();
-        let quadratic_formula_numerator_plus = quadratic_formula_num_b
-            + (quadratic_formula_num_b_squared - quadratic_formula_4ac).sqrt();
-        let quadratic_formula_divisor = 2.0 * quadratic_eq_a;
-
-        let breakeven_price_point_one =
-            quadratic_formula_numerator_minus / quadratic_formula_divisor;
-        let breakeven_price_point_two =
-            quadratic_formula_numerator_plus / quadratic_formula_divisor;
-
-        // Substitute breakeven_price_point_one at revenue function to get quantity of money need to reach for breakeven
-        let breakeven_qty_one =
-            (demand_m * breakeven_price_point_one.powi(2)) + (demand_b * breakeven_price_point_one);
-        // Substitute breakeven_price_point_two at revenue function to get quantity of money need to reach for breakeven
-        let breakeven_qty_two =
-            (demand_m * breakeven_price_point_two.powi(2)) + (demand_b * breakeven_price_point_two);
-
-        let breakeven_points = [
-            Circle::new(
-                (breakeven_price_point_one, breakeven_qty_one),
-                5,
-                BLUE.filled(),
-            ),
-            Circle::new(
-                (breakeven_price_point_two, breakeven_qty_two),
-                5,
-                BLUE.filled(),
-            ),
-        ];
+        // Get demand function
+        let (demand_m, demand_b) = linreg::Linreg::linear_regress(prices, quantity_purchase)?;
 
-        /*** Get profit equation ***/
-        // P = R - E
-        let profit_a = &demand_m;
-        let profit_b = demand_b + (-1.0 * em);
-        let profit_c = -1.0 * eb;
+        let start_price = prices.iter().cloned().fold(f32::INFINITY, f32::min);
+        let end_price = prices.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+        let max_range = quantity_purchase
+            .iter()
+            .cloned()
+            .fold(f32::NEG_INFINITY, f32::max)
+            .max((demand_m * start_price) + demand_b)
+            .max((demand_m * end_price) + demand_b);
 
-        let axis_of_symmetry_profit_price = profit_b.neg() / (2.0 * profit_a);
+        // Setup filepath / directory on which folder to save it
+        let dt = Utc::now();
+        let timestamp: i64 = dt.timestamp();
 
-        // Quadratic formula
-        let max_profit = (profit_a * axis_of_symmetry_profit_price.powi(2))
-            + (profit_b * axis_of_symmetry_profit_price)
-            + profit_c;
+        let dir = directory.unwrap_or("chart_outputs".to_string());
 
-        let max_profit_point = [Circle::new(
-            (axis_of_symmetry_profit_price, max_profit),
-            5,
-            ORANGE.filled(),
-        )];
+        fs::create_dir_all(&dir)?;
 
-        let x_spec = 0.0..horizontal_axis;
-        let y_spec = 0.0..vertical_axis;
+        let filepath = format!("{}/{}_demand_elasticity.png", &dir, timestamp);
 
-        let expense_line_data = (0..=horizontal_axis.to_i32().unwrap())
-            .collect::<Vec<i32>>()
-            .iter()
-            .map(|x| (x.to_f32().unwrap(), ((em * x.to_f32().unwrap()) + eb)))
-            .collect::<Vec<(f32, f32)>>();
+        // Build drawing area
+        let drawing_area =
+            BitMapBackend::new(&filepath, (height.unwrap_or(1024), width.unwrap_or(768)))
+                .into_drawing_area();
 
-        let expense_regression_line = LineSeries::new(expense_line_data, GREEN.stroke_width(2));
+        drawing_area.fill(&WHITE)?;
 
-        let revenue_line_data = (0..=horizontal_axis.to_i32().unwrap())
-            .collect::<Vec<i32>>()
-            .iter()
-            .map(|x| {
-                let x_f32 = x.to_f32().unwrap();
+        let x_spec = start_price..end_price;
+        let y_spec = 0.0..max_range;
 
-                (
-                    x.to_f32().unwrap(),
-                    (demand_m * x_f32.powf(2.0)) + (demand_b * x_f32),
-                )
-            })
-            .collect::<Vec<(f32, f32)>>();
+        // Set title at top of the graph
+        let caption = format!("{} Demand Elasticity", title);
+        let font_style = ("sans-serif", 25.0).into_font();
+
+        // Set x and y labels
+        let mut chart_builder = ChartBuilder::on(&drawing_area);
+        let mut scatterplot = chart_builder
+            .margin(15)
+            .x_label_area_size(40)
+            .y_label_area_size(40)
+            .caption(caption, font_style)
+            .build_cartesian_2d(x_spec, y_spec)?;
 
-        let revenue_regression_line = LineSeries::new(revenue_line_data, PURPLE.stroke_width(2));
+        scatterplot
+            .configure_mesh()
+            .disable_x_mesh()
+            .disable_y_mesh()
+            .x_desc("Price")
+            .y_desc("Quantity")
+            .draw()?;
 
-        let profit_line_data = (0..=horizontal_axis.to_i32().unwrap())
-            .collect::<Vec<i32>>()
+        // Shade inelastic and elastic price ranges behind the demand line
+        if shade_elasticity {
+            for elasticity_range in
+                Elasticity::get_elasticity_ranges(demand_m, demand_b, start_price, end_price)
+            {
+                let (color, label) = match elasticity_range.class {
+                    ElasticityClass::Elastic => (RED, "Elastic"),
+                    _ => (BLUE, "Inelastic"),
+                };
+
+                scatterplot
+                    .draw_series([Rectangle::new(
+                        [
+                            (elasticity_range.start_price, 0.0),
+                            (elasticity_range.end_price, max_range),
+                        ],
+                        color.mix(0.15).filled(),
+                    )])?
+                    .label(label)
+                    .legend(move |(x, y)| {
+                        Rectangle::new([(x, y - 5), (x + 20, y + 5)], color.mix(0.15).filled())
+                    });
+            }
+        }
+
+        let circles = prices
             .iter()
-            .map(|x| {
-                let x_f32 = x.to_f32().unwrap();
+            .zip(quantity_purchase.iter())
+            .map(|(price, quantity)| Circle::new((*price, *quantity), 5, GREEN.filled()));
 
-                (
-                    x_f32,
-                    (profit_a * x_f32.powf(2.0)) + (profit_b * x_f32) + profit_c,
-                )
-            })
-            .collect::<Vec<(f32, f32)>>();
+        scatterplot.draw_series(circles)?;
+
+        let demand_line = LineSeries::new(
+            vec![
+                (start_price, (demand_m * start_price) + demand_b),
+                (end_price, (demand_m * end_price) + demand_b),
+            ],
+            BLUE.stroke_width(2),
+        );
+
+        scatterplot
+            .draw_series(demand_line)?
+            .label("Demand")
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+
+        scatterplot
+            .configure_series_labels()
+            .background_style(WHITE.mix(0.8))
+            .border_style(BLACK)
+            .draw()?;
+
+        drawing_area
+            .present()
+            .unwrap_or_else(|_| panic!("Cannot write into {:?}. Directory does not exists.", &dir));
+
+        println!("Demand Elasticity Graph has been saved to {}", filepath);
+
+        Ok(true)
+    }
+
+    pub fn intervention_scatterplot(
+        market: &Market,
+        intervention: Intervention,
+        title: String,
+        directory: Option<String>,
+        height: Option<u32>,
+        width: Option<u32>,
+    ) -> Result<bool, Box<dyn Error>> {
+        let outcome = match market.apply_intervention(intervention) {
+            Some(outcome) => outcome,
+            None => Err("Market has no equilibrium")?,
+        };
+        let shifted_market = market.get_shifted_supply(intervention);
+
+        // Plot from zero up to the price where demand runs out
+        let end_price = (-market.demand_b / market.demand_m).max(outcome.buyer_price) * 1.1;
+        let max_range = market
+            .get_demand(0.0)
+            .max(market.get_supply(end_price))
+            .max(shifted_market.get_supply(end_price));
+
+        // Setup filepath / directory on which folder to save it
+        let dt = Utc::now();
+        let timestamp: i64 = dt.timestamp();
+
+        let dir = directory.unwrap_or("chart_outputs".to_string());
+
+        fs::create_dir_all(&dir)?;
+
+        let filepath = format!("{}/{}_intervention.png", &dir, timestamp);
+
+        // Build drawing area
+        let drawing_area =
+            BitMapBackend::new(&filepath, (height.unwrap_or(1024), width.unwrap_or(768)))
+                .into_drawing_area();
+
+        drawing_area.fill(&WHITE)?;
+
+        let x_spec = 0.0..end_price;
+        let y_spec = 0.0..max_range;
+
+        // Set title at top of the graph
+        let caption = format!("{} Market Intervention", title);
+        let font_style = ("sans-serif", 25.0).into_font();
+
+        // Set x and y labels
+        let mut chart_builder = ChartBuilder::on(&drawing_area);
+        let mut scatterplot = chart_builder
+            .margin(15)
+            .x_label_area_size(40)
+            .y_label_area_size(40)
+            .caption(caption, font_style)
+            .build_cartesian_2d(x_spec, y_spec)?;
+
+        scatterplot
+            .configure_mesh()
+            .disable_x_mesh()
+            .disable_y_mesh()
+            .x_desc("Price")
+            .y_desc("Quantity")
+            .draw()?;
+
+        // Sample the curves and drop points off the chart so they are not clamped onto its edges
+        let line = |market: &Market, demand: bool| {
+            (0..=200)
+                .map(|step| {
+                    let price = end_price * step as f32 / 200.0;
+                    let quantity = if demand {
+                        market.get_demand(price)
+                    } else {
+                        market.get_supply(price)
+                    };
+                    (price, quantity)
+                })
+                .filter(|(_, quantity)| *quantity >= 0.0 && *quantity <= max_range)
+                .collect::<Vec<(f32, f32)>>()
+        };
+
+        scatterplot
+            .draw_series(LineSeries::new(line(market, true), BLUE.stroke_width(2)))?
+            .label("Demand")
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+
+        scatterplot
+            .draw_series(LineSeries::new(line(market, false), GREEN.stroke_width(2)))?
+            .label("Supply")
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], GREEN));
+
+        match intervention {
+            Intervention::Tax(_) | Intervention::Subsidy(_) => {
+                scatterplot
+                    .draw_series(LineSeries::new(
+                        line(&shifted_market, false),
+                        ORANGE.stroke_width(2),
+                    ))?
+                    .label("Shifted Supply")
+                    .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], ORANGE));
+            }
+            Intervention::PriceCeiling(price) | Intervention::PriceFloor(price) => {
+                scatterplot
+                    .draw_series(LineSeries::new(
+                        vec![(price, 0.0), (price, max_range)],
+                        RED.stroke_width(2),
+                    ))?
+                    .label("Price Control")
+                    .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+            }
+        }
+
+        // Buyers pay buyer_price and sellers receive seller_price for the quantity traded
+        scatterplot
+            .draw_series([
+                Circle::new((outcome.buyer_price, outcome.quantity), 5, PURPLE.filled()),
+                Circle::new((outcome.seller_price, outcome.quantity), 5, PURPLE.filled()),
+            ])?
+            .label(format!(
+                "Quantity {:.2}, Deadweight Loss {:.2}",
+                outcome.quantity, outcome.deadweight_loss
+            ))
+            .legend(|(x, y)| Circle::new((x + 10, y), 5, PURPLE.filled()));
+
+        scatterplot
+            .configure_series_labels()
+            .background_style(WHITE.mix(0.8))
+            .border_style(BLACK)
+            .draw()?;
+
+        drawing_area
+            .present()
+            .unwrap_or_else(|_| panic!("Cannot write into {:?}. Directory does not exists.", &dir));
+
+        println!("Market Intervention Graph has been saved to {}", filepath);
 
-        let profit_regression_line = LineSeries::new(profit_line_data, BROWN.stroke_width(2));
+        Ok(true)
+    }
+
+    pub fn cost_model_scatterplot(
+        prices: &[f32],
+        quantity_purchase: &[f32],
+        cost_model: &CostModel,
+        title: String,
+        directory: Option<String>,
+        height: Option<u32>,
+        width: Option<u32>,
+    ) -> Result<bool, Box<dyn Error>> {
+        let analysis = cost_model.get_profit_analysis(prices, quantity_purchase, 500)?;
+
+        let end_price = analysis
+            .points
+            .last()
+            .map(|point| point.price)
+            .unwrap_or(0.0);
+        let min_range = analysis
+            .points
+            .iter()
+            .fold(0.0, |acc: f32, point| acc.min(point.profit));
+        let max_range = analysis.points.iter().fold(0.0, |acc: f32, point| {
+            acc.max(point.revenue).max(point.cost)
+        });
 
         // Setup filepath / directory on which folder to save it
         let dt = Utc::now();
@@ -454,6 +609,9 @@ impl BusinessModelling {
 
         drawing_area.fill(&WHITE)?;
 
+        let x_spec = 0.0..end_price;
+        let y_spec = min_range..max_range;
+
         // Set title at top of the graph
         let caption = format!("{} Expense & Revenue", title);
         let font_style = ("sans-serif", 25.0).into_font();
@@ -475,52 +633,108 @@ impl BusinessModelling {
             .y_desc("Fiat")
             .draw()?;
 
+        let expense_line_data = analysis
+            .points
+            .iter()
+            .map(|point| (point.price, point.cost))
+            .collect::<Vec<(f32, f32)>>();
+        let revenue_line_data = analysis
+            .points
+            .iter()
+            .map(|point| (point.price, point.revenue))
+            .collect::<Vec<(f32, f32)>>();
+        let profit_line_data = analysis
+            .points
+            .iter()
+            .map(|point| (point.price, point.profit))
+            .collect::<Vec<(f32, f32)>>();
+
         scatterplot
-            .draw_series(expense_regression_line)?
+            .draw_series(LineSeries::new(expense_line_data, GREEN.stroke_width(2)))?
             .label("Expense")
-            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &GREEN));
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], GREEN));
 
         scatterplot
-            .draw_series(revenue_regression_line)?
+            .draw_series(LineSeries::new(revenue_line_data, PURPLE.stroke_width(2)))?
             .label("Revenue")
-            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &PURPLE));
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], PURPLE));
 
         scatterplot
-            .draw_series(profit_regression_line)?
+            .draw_series(LineSeries::new(profit_line_data, BROWN.stroke_width(2)))?
             .label("Profit")
-            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BROWN));
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BROWN));
 
-        scatterplot
-            .draw_series(max_revenue_point)?
-            .label("Max Revenue")
-            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &RED));
+        if let Some(max_revenue) = analysis
+            .points
+            .iter()
+            .max_by(|a, b| a.revenue.total_cmp(&b.revenue))
+        {
+            scatterplot
+                .draw_series([Circle::new(
+                    (max_revenue.price, max_revenue.revenue),
+                    5,
+                    RED.filled(),
+                )])?
+                .label("Max Revenue")
+                .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+        }
 
         scatterplot
-            .draw_series(max_profit_point)?
+            .draw_series([Circle::new(
+                (analysis.max_profit.price, analysis.max_profit.profit),
+                5,
+                ORANGE.filled(),
+            )])?
             .label("Max Profit")
-            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &ORANGE));
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], ORANGE));
 
         scatterplot
-            .draw_series(breakeven_points)?
+            .draw_series(
+                analysis
+                    .breakeven_prices
+                    .iter()
+                    .map(|price| Circle::new((*price, 0.0), 5, BLUE.filled())),
+            )?
             .label(format!(
-                "Breakeven Points [{},{}]",
-                breakeven_qty_one, breakeven_qty_two
+                "Breakeven Prices {:.2?}",
+                analysis.breakeven_prices
             ))
-            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BLUE));
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
 
         scatterplot
             .configure_series_labels()
-            .background_style(&WHITE.mix(0.8))
-            .border_style(&BLACK)
+            .background_style(WHITE.mix(0.8))
+            .border_style(BLACK)
             .draw()?;
 
-        drawing_area.present().expect(&format!(
-            "Cannot write into {:?}. Directory does not exists.",
-            &dir
-        ));
+        drawing_area
+            .present()
+            .unwrap_or_else(|_| panic!("Cannot write into {:?}. Directory does not exists.", &dir));
 
         println!("Business Model has been saved to {}", filepath);
 
-        return Ok(true);
+        Ok(true)
+    }
+
+    // Linear expense of manufacturing_cost per unit on top of fixed_cost, see cost_model_scatterplot.
+    pub fn model(
+        prices: &[f32],
+        quantity_purchase: &[f32],
+        fixed_cost: &f32,
+        manufacturing_cost: &f32,
+        title: String,
+        directory: Option<String>,
+        height: Option<u32>,
+        width: Option<u32>,
+    ) -> Result<bool, Box<dyn Error>> {
+        Self::cost_model_scatterplot(
+            prices,
+            quantity_purchase,
+            &CostModel::from_linear(*fixed_cost, *manufacturing_cost),
+            title,
+            directory,
+            height,
+            width,
+        )
     }
 }
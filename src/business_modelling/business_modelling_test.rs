@@ -147,3 +147,64 @@ fn it_successfully_shows_business_model_graph() {
         Err(err) => println!("Error in showing supply and demand scatterplot {:?}", err),
     }
 }
+
+#[test]
+fn it_successfully_shows_demand_elasticity_graph() {
+    let prices = vec![15.25, 15.50, 15.75, 16.00, 16.25, 16.50, 16.75, 17.00];
+    let quantity_purchase = vec![
+        3456.00, 3005.00, 2546.00, 2188.00, 1678.00, 1290.00, 889.00, 310.00,
+    ];
+
+    let result = business_modelling::BusinessModelling::demand_elasticity_scatterplot(
+        &prices,
+        &quantity_purchase,
+        true,
+        "Pet Store".to_string(),
+        None,
+        None,
+        None,
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn it_successfully_shows_intervention_graph() {
+    let market = crate::business_modelling::equilibrium::Market::new(-2.0, 100.0, 3.0, -50.0);
+
+    let result = business_modelling::BusinessModelling::intervention_scatterplot(
+        &market,
+        crate::business_modelling::intervention::Intervention::Tax(5.0),
+        "Pet Store".to_string(),
+        None,
+        None,
+        None,
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn it_successfully_shows_cost_model_graph() {
+    let prices = vec![10.0, 20.0, 30.0, 40.0];
+    let quantity_purchase = vec![80.0, 60.0, 40.0, 20.0];
+
+    let mut cost_model = crate::business_modelling::cost_model::CostModel::from_linear(200.0, 10.0);
+    cost_model
+        .add_component(
+            crate::business_modelling::cost_model::CostComponent::SteppedFixed {
+                cost_per_step: 100.0,
+                units_per_step: 25.0,
+            },
+        )
+        .unwrap();
+
+    let result = business_modelling::BusinessModelling::cost_model_scatterplot(
+        &prices,
+        &quantity_purchase,
+        &cost_model,
+        "Pet Store".to_string(),
+        None,
+        None,
+        None,
+    );
+    assert!(result.is_ok());
+}
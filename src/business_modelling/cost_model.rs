@@ -0,0 +1,268 @@
+use std::error::Error;
+
+use crate::business_modelling::linreg;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CostComponent {
+    Fixed(f32),
+    // Cost per unit produced.
+    Variable(f32),
+    // cost_per_step for every started batch of units_per_step units, e.g. a new machine every 1,000 units.
+    SteppedFixed {
+        cost_per_step: f32,
+        units_per_step: f32,
+    },
+    // (from_quantity, unit_cost) tiers ordered by from_quantity, units from each threshold on cost that tier's
+    // unit_cost. Units below the first threshold cost the first tier's unit_cost.
+    VolumeDiscount(Vec<(f32, f32)>),
+    // base covers up to included_units, every unit above costs unit_cost, e.g. salaried labour plus overtime.
+    SemiVariable {
+        base: f32,
+        included_units: f32,
+        unit_cost: f32,
+    },
+}
+
+impl CostComponent {
+    // Steps need a positive batch size and volume tiers have to be in increasing from_quantity order.
+    pub fn validate(&self) -> Result<(), Box<dyn Error>> {
+        match self {
+            CostComponent::SteppedFixed { units_per_step, .. } => {
+                if *units_per_step <= 0.0 {
+                    Err("Units per step must be positive")?;
+                }
+            }
+            CostComponent::VolumeDiscount(tiers) => {
+                if tiers.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+                    Err("Volume discount tiers must be sorted by increasing quantity")?;
+                }
+            }
+            CostComponent::Fixed(_)
+            | CostComponent::Variable(_)
+            | CostComponent::SemiVariable { .. } => {}
+        }
+
+        Ok(())
+    }
+
+    pub fn get_cost(&self, quantity: f32) -> f32 {
+        let quantity = quantity.max(0.0);
+
+        match self {
+            CostComponent::Fixed(cost) => *cost,
+            CostComponent::Variable(unit_cost) => unit_cost * quantity,
+            CostComponent::SteppedFixed {
+                cost_per_step,
+                units_per_step,
+            } => cost_per_step * (quantity / units_per_step).ceil(),
+            CostComponent::VolumeDiscount(tiers) => {
+                tiers
+                    .iter()
+                    .enumerate()
+                    .fold(0.0, |acc, (idx, (from_quantity, unit_cost))| {
+                        let start = if idx == 0 { 0.0 } else { *from_quantity };
+                        let end = match tiers.get(idx + 1) {
+                            Some((next_quantity, _)) => quantity.min(*next_quantity),
+                            None => quantity,
+                        };
+                        acc + (unit_cost * (end - start).max(0.0))
+                    })
+            }
+            CostComponent::SemiVariable {
+                base,
+                included_units,
+                unit_cost,
+            } => base + (unit_cost * (quantity - included_units).max(0.0)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProfitPoint {
+    pub price: f32,
+    pub quantity: f32,
+    pub revenue: f32,
+    pub cost: f32,
+    pub profit: f32,
+}
+
+// points are sampled from price 0 up to the price where demand runs out.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProfitAnalysis {
+    pub points: Vec<ProfitPoint>,
+    pub max_profit: ProfitPoint,
+    pub breakeven_prices: Vec<f32>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CostModel {
+    components: Vec<CostComponent>,
+}
+
+impl CostModel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Same costs as the fixed_cost and manufacturing_cost of BusinessModelling::model
+    pub fn from_linear(fixed_cost: f32, manufacturing_cost: f32) -> Self {
+        Self {
+            components: vec![
+                CostComponent::Fixed(fixed_cost),
+                CostComponent::Variable(manufacturing_cost),
+            ],
+        }
+    }
+
+    pub fn add_component(&mut self, component: CostComponent) -> Result<(), Box<dyn Error>> {
+        component.validate()?;
+        self.components.push(component);
+
+        Ok(())
+    }
+
+    pub fn get_components(&self) -> &Vec<CostComponent> {
+        &self.components
+    }
+
+    // Only fixed and variable costs, so the total cost is a straight line in quantity.
+    pub fn is_linear(&self) -> bool {
+        self.components.iter().all(|component| {
+            matches!(
+                component,
+                CostComponent::Fixed(_) | CostComponent::Variable(_)
+            )
+        })
+    }
+
+    pub fn get_total_cost(&self, quantity: f32) -> f32 {
+        self.components
+            .iter()
+            .fold(0.0, |acc, component| acc + component.get_cost(quantity))
+    }
+
+    // Cost of producing nothing
+    pub fn get_fixed_cost(&self) -> f32 {
+        self.get_total_cost(0.0)
+    }
+
+    pub fn get_variable_cost(&self, quantity: f32) -> f32 {
+        self.get_total_cost(quantity) - self.get_fixed_cost()
+    }
+
+    pub fn get_average_cost(&self, quantity: f32) -> Option<f32> {
+        if quantity <= 0.0 {
+            return None;
+        }
+
+        Some(self.get_total_cost(quantity) / quantity)
+    }
+
+    // Cost of producing one more unit
+    pub fn get_marginal_cost(&self, quantity: f32) -> f32 {
+        self.get_total_cost(quantity + 1.0) - self.get_total_cost(quantity)
+    }
+
+    pub fn get_profit_point(&self, demand_m: f32, demand_b: f32, price: f32) -> ProfitPoint {
+        let quantity = ((demand_m * price) + demand_b).max(0.0);
+        let revenue = price * quantity;
+        let cost = self.get_total_cost(quantity);
+
+        ProfitPoint {
+            price,
+            quantity,
+            revenue,
+            cost,
+            profit: revenue - cost,
+        }
+    }
+
+    // Profit over prices on the demand fitted from the observations. Break-even prices of a linear cost model are
+    // the roots of its quadratic profit, otherwise they are interpolated where the profit changes sign between two
+    // samples.
+    pub fn get_profit_analysis(
+        &self,
+        prices: &[f32],
+        quantity_purchase: &[f32],
+        samples: u32,
+    ) -> Result<ProfitAnalysis, Box<dyn Error>> {
+        if samples < 2 {
+            Err("At least two samples are needed")?;
+        }
+
+        let (demand_m, demand_b) =
+            linreg::Linreg::linear_regress(&prices.to_vec(), &quantity_purchase.to_vec())?;
+        if demand_m >= 0.0 {
+            Err("Demand does not fall with price")?;
+        }
+
+        let choke_price = -demand_b / demand_m;
+        let points = (0..=samples)
+            .map(|idx| {
+                let price = choke_price * idx as f32 / samples as f32;
+                self.get_profit_point(demand_m, demand_b, price)
+            })
+            .collect::<Vec<ProfitPoint>>();
+
+        let max_profit = points
+            .iter()
+            .cloned()
+            .reduce(|max, point| {
+                if point.profit > max.profit {
+                    point
+                } else {
+                    max
+                }
+            })
+            .ok_or("No profit points")?;
+
+        let breakeven_prices = if self.is_linear() {
+            self.get_linear_breakeven_prices(demand_m, demand_b, choke_price)
+        } else {
+            points
+                .windows(2)
+                .filter(|pair| (pair[0].profit < 0.0) != (pair[1].profit < 0.0))
+                .map(|pair| {
+                    let share = pair[0].profit / (pair[0].profit - pair[1].profit);
+                    pair[0].price + ((pair[1].price - pair[0].price) * share)
+                })
+                .collect()
+        };
+
+        Ok(ProfitAnalysis {
+            points,
+            max_profit,
+            breakeven_prices,
+        })
+    }
+
+    // Revenue equals cost where demand_m * p^2 + (demand_b - unit_cost * demand_m) * p - (fixed_cost + unit_cost *
+    // demand_b) is zero. A tangent root is left out, the same as when sampling since the profit does not change sign.
+    fn get_linear_breakeven_prices(
+        &self,
+        demand_m: f32,
+        demand_b: f32,
+        choke_price: f32,
+    ) -> Vec<f32> {
+        let fixed_cost = self.get_fixed_cost();
+        let unit_cost = self.get_variable_cost(1.0);
+
+        let quadratic_eq_a = demand_m;
+        let quadratic_eq_b = demand_b - (unit_cost * demand_m);
+        let quadratic_eq_c = -(fixed_cost + (unit_cost * demand_b));
+
+        let discriminant = quadratic_eq_b.powi(2) - (4.0 * quadratic_eq_a * quadratic_eq_c);
+        if discriminant <= 0.0 {
+            return vec![];
+        }
+
+        // demand_m is negative, so adding the square root gives the lower price.
+        [
+            (-quadratic_eq_b + discriminant.sqrt()) / (2.0 * quadratic_eq_a),
+            (-quadratic_eq_b - discriminant.sqrt()) / (2.0 * quadratic_eq_a),
+        ]
+        .into_iter()
+        .filter(|price| (0.0..=choke_price).contains(price))
+        .collect()
+    }
+}
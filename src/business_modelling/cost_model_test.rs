@@ -0,0 +1,128 @@
+#[cfg(test)]
+use crate::business_modelling::{assert_close, cost_model::*};
+
+#[test]
+fn it_computes_linear_costs() {
+    let cost_model = CostModel::from_linear(100.0, 2.0);
+
+    assert_eq!(cost_model.get_total_cost(10.0), 120.0);
+    assert_eq!(cost_model.get_fixed_cost(), 100.0);
+    assert_eq!(cost_model.get_variable_cost(10.0), 20.0);
+    assert_eq!(cost_model.get_average_cost(10.0), Some(12.0));
+    assert_eq!(cost_model.get_average_cost(0.0), None);
+    assert_eq!(cost_model.get_marginal_cost(10.0), 2.0);
+}
+
+#[test]
+fn it_adds_a_step_for_every_started_batch() {
+    let machine = CostComponent::SteppedFixed {
+        cost_per_step: 1000.0,
+        units_per_step: 100.0,
+    };
+
+    assert_eq!(machine.get_cost(0.0), 0.0);
+    assert_eq!(machine.get_cost(100.0), 1000.0);
+    assert_eq!(machine.get_cost(150.0), 2000.0);
+}
+
+#[test]
+fn it_discounts_units_above_each_volume_tier() {
+    let materials = CostComponent::VolumeDiscount(vec![(0.0, 5.0), (100.0, 4.0), (500.0, 3.0)]);
+
+    assert_eq!(materials.get_cost(50.0), 250.0);
+    assert_eq!(materials.get_cost(600.0), 2400.0);
+}
+
+#[test]
+fn it_rejects_invalid_components() {
+    let mut cost_model = CostModel::new();
+
+    assert!(cost_model
+        .add_component(CostComponent::SteppedFixed {
+            cost_per_step: 1000.0,
+            units_per_step: 0.0,
+        })
+        .is_err());
+    assert!(cost_model
+        .add_component(CostComponent::VolumeDiscount(vec![
+            (0.0, 5.0),
+            (500.0, 3.0),
+            (100.0, 4.0),
+        ]))
+        .is_err());
+    assert!(cost_model.get_components().is_empty());
+}
+
+#[test]
+fn it_charges_semi_variable_costs_above_included_units() {
+    let labour = CostComponent::SemiVariable {
+        base: 2000.0,
+        included_units: 160.0,
+        unit_cost: 20.0,
+    };
+
+    assert_eq!(labour.get_cost(100.0), 2000.0);
+    assert_eq!(labour.get_cost(200.0), 2800.0);
+
+    let mut cost_model = CostModel::new();
+    cost_model.add_component(labour).unwrap();
+    cost_model
+        .add_component(CostComponent::Variable(5.0))
+        .unwrap();
+    assert_eq!(cost_model.get_fixed_cost(), 2000.0);
+    assert_eq!(cost_model.get_marginal_cost(100.0), 5.0);
+    assert_eq!(cost_model.get_marginal_cost(200.0), 25.0);
+}
+
+#[test]
+fn it_finds_max_profit_and_breakeven_prices() {
+    // Demand is -2 * price + 100, profit -2 * price^2 + 120 * price - 1200
+    let prices = vec![10.0, 20.0, 30.0, 40.0];
+    let quantity_purchase = vec![80.0, 60.0, 40.0, 20.0];
+    let cost_model = CostModel::from_linear(200.0, 10.0);
+
+    let analysis = cost_model
+        .get_profit_analysis(&prices, &quantity_purchase, 1000)
+        .unwrap();
+
+    assert_close(analysis.max_profit.price, 30.0);
+    assert_close(analysis.max_profit.profit, 600.0);
+    assert_eq!(analysis.breakeven_prices.len(), 2);
+    assert_close(analysis.breakeven_prices[0], 30.0 - 300.0_f32.sqrt());
+    assert_close(analysis.breakeven_prices[1], 30.0 + 300.0_f32.sqrt());
+}
+
+#[test]
+fn it_solves_linear_breakeven_prices_regardless_of_samples() {
+    let prices = vec![10.0, 20.0, 30.0, 40.0];
+    let quantity_purchase = vec![80.0, 60.0, 40.0, 20.0];
+    let cost_model = CostModel::from_linear(200.0, 10.0);
+    let mut stepped_cost_model = CostModel::from_linear(200.0, 10.0);
+    stepped_cost_model
+        .add_component(CostComponent::SteppedFixed {
+            cost_per_step: 50.0,
+            units_per_step: 40.0,
+        })
+        .unwrap();
+
+    let analysis = cost_model
+        .get_profit_analysis(&prices, &quantity_purchase, 2)
+        .unwrap();
+
+    assert!(cost_model.is_linear());
+    assert!(!stepped_cost_model.is_linear());
+    assert_eq!(analysis.breakeven_prices.len(), 2);
+    assert_close(analysis.breakeven_prices[0], 30.0 - 300.0_f32.sqrt());
+    assert_close(analysis.breakeven_prices[1], 30.0 + 300.0_f32.sqrt());
+}
+
+#[test]
+fn it_returns_error_because_demand_does_not_fall() {
+    let cost_model = CostModel::from_linear(200.0, 10.0);
+    let result = cost_model.get_profit_analysis(&[10.0, 20.0], &[10.0, 20.0], 100);
+
+    match result {
+        Ok(_) => panic!("Expected an error"),
+        Err(err) => assert_eq!(err.to_string(), "Demand does not fall with price"),
+    }
+}
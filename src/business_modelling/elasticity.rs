@@ -0,0 +1,164 @@
+use std::error::Error;
+
+use crate::business_modelling::linreg;
+
+// |elasticity| within this distance of 1 is taken as unit elastic.
+const UNIT_ELASTIC_TOLERANCE: f32 = 0.001;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ElasticityClass {
+    PerfectlyInelastic,
+    Inelastic,
+    UnitElastic,
+    Elastic,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArcElasticity {
+    pub start_price: f32,
+    pub end_price: f32,
+    pub elasticity: f32,
+    pub class: ElasticityClass,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ElasticityRange {
+    pub start_price: f32,
+    pub end_price: f32,
+    pub class: ElasticityClass,
+}
+
+#[derive(Debug)]
+pub struct Elasticity {}
+
+// Price elasticity of demand, quantity = (demand_m * price) + demand_b as fitted by Linreg
+impl Elasticity {
+    // (dQ/dP) * (P / Q), None where the fitted demand is zero or negative
+    pub fn get_point_elasticity(demand_m: f32, demand_b: f32, price: f32) -> Option<f32> {
+        let quantity = (demand_m * price) + demand_b;
+        if quantity <= 0.0 {
+            return None;
+        }
+
+        Some(demand_m * price / quantity)
+    }
+
+    // Midpoint formula, the same value whichever way the price moved
+    pub fn get_arc_elasticity(
+        start_price: f32,
+        start_quantity: f32,
+        end_price: f32,
+        end_quantity: f32,
+    ) -> Option<f32> {
+        let price_sum = start_price + end_price;
+        let quantity_sum = start_quantity + end_quantity;
+        if start_price == end_price || price_sum == 0.0 || quantity_sum == 0.0 {
+            return None;
+        }
+
+        let quantity_change = (end_quantity - start_quantity) / (quantity_sum / 2.0);
+        let price_change = (end_price - start_price) / (price_sum / 2.0);
+
+        Some(quantity_change / price_change)
+    }
+
+    // Arc elasticity between each pair of consecutive observations, pairs at the same price are skipped
+    pub fn get_observed_arc_elasticities(
+        prices: &[f32],
+        quantity_purchase: &[f32],
+    ) -> Result<Vec<ArcElasticity>, Box<dyn Error>> {
+        if prices.len() < 2 || quantity_purchase.len() < 2 {
+            // Check if there are at least two observations
+            Err("Insufficient lengths")?;
+        } else if prices.len() != quantity_purchase.len() {
+            // Check if prices and quantities has the same lengths
+            Err("Range length is not equal to domain length or vice versa")?;
+        }
+
+        Ok((1..prices.len())
+            .filter_map(|idx| {
+                let elasticity = Self::get_arc_elasticity(
+                    prices[idx - 1],
+                    quantity_purchase[idx - 1],
+                    prices[idx],
+                    quantity_purchase[idx],
+                )?;
+
+                Some(ArcElasticity {
+                    start_price: prices[idx - 1],
+                    end_price: prices[idx],
+                    elasticity,
+                    class: Self::classify(elasticity),
+                })
+            })
+            .collect())
+    }
+
+    // Point elasticity at price on the demand fitted from the observations
+    pub fn get_demand_elasticity(
+        prices: &[f32],
+        quantity_purchase: &[f32],
+        price: f32,
+    ) -> Result<Option<f32>, Box<dyn Error>> {
+        let (demand_m, demand_b) =
+            linreg::Linreg::linear_regress(&prices.to_vec(), &quantity_purchase.to_vec())?;
+
+        Ok(Self::get_point_elasticity(demand_m, demand_b, price))
+    }
+
+    pub fn classify(elasticity: f32) -> ElasticityClass {
+        let elasticity = elasticity.abs();
+
+        if elasticity == 0.0 {
+            ElasticityClass::PerfectlyInelastic
+        } else if (elasticity - 1.0).abs() <= UNIT_ELASTIC_TOLERANCE {
+            ElasticityClass::UnitElastic
+        } else if elasticity < 1.0 {
+            ElasticityClass::Inelastic
+        } else {
+            ElasticityClass::Elastic
+        }
+    }
+
+    // On a straight demand line |elasticity| is 1 halfway to the price intercept (-b / 2m), which is also
+    // where revenue peaks.
+    pub fn get_unit_elastic_price(demand_m: f32, demand_b: f32) -> Option<f32> {
+        if demand_m >= 0.0 {
+            return None;
+        }
+
+        Some(-demand_b / (2.0 * demand_m))
+    }
+
+    // Demand is inelastic below the unit elastic price and elastic above it
+    pub fn get_elasticity_ranges(
+        demand_m: f32,
+        demand_b: f32,
+        start_price: f32,
+        end_price: f32,
+    ) -> Vec<ElasticityRange> {
+        let range = |start_price: f32, end_price: f32, class: ElasticityClass| ElasticityRange {
+            start_price,
+            end_price,
+            class,
+        };
+
+        match Self::get_unit_elastic_price(demand_m, demand_b) {
+            Some(unit_price) if unit_price > start_price && unit_price < end_price => vec![
+                range(start_price, unit_price, ElasticityClass::Inelastic),
+                range(unit_price, end_price, ElasticityClass::Elastic),
+            ],
+            Some(unit_price) if unit_price >= end_price => {
+                vec![range(start_price, end_price, ElasticityClass::Inelastic)]
+            }
+            Some(_) => vec![range(start_price, end_price, ElasticityClass::Elastic)],
+            None if demand_m == 0.0 => vec![range(
+                start_price,
+                end_price,
+                ElasticityClass::PerfectlyInelastic,
+            )],
+            // Upward sloping demand has no elasticity ranges
+            None => vec![],
+        }
+    }
+}
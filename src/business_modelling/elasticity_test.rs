@@ -0,0 +1,85 @@
+#[cfg(test)]
+use crate::business_modelling::elasticity::*;
+
+// Demand fitted as quantity = -2 * price + 100
+#[cfg(test)]
+fn generate_demand() -> (Vec<f32>, Vec<f32>) {
+    let prices = vec![10.0, 20.0, 30.0, 40.0];
+    let quantity_purchase = vec![80.0, 60.0, 40.0, 20.0];
+    (prices, quantity_purchase)
+}
+
+#[test]
+fn it_computes_point_elasticity_on_the_fitted_demand() {
+    let (prices, quantity_purchase) = generate_demand();
+
+    let elasticity = Elasticity::get_demand_elasticity(&prices, &quantity_purchase, 10.0)
+        .unwrap()
+        .unwrap();
+    assert!((elasticity + 0.25).abs() < 1e-5);
+    assert_eq!(Elasticity::classify(elasticity), ElasticityClass::Inelastic);
+
+    let elasticity = Elasticity::get_point_elasticity(-2.0, 100.0, 25.0).unwrap();
+    assert_eq!(
+        Elasticity::classify(elasticity),
+        ElasticityClass::UnitElastic
+    );
+
+    // No demand left at the price intercept
+    assert_eq!(Elasticity::get_point_elasticity(-2.0, 100.0, 50.0), None);
+}
+
+#[test]
+fn it_computes_arc_elasticities_between_observations() {
+    let (prices, quantity_purchase) = generate_demand();
+
+    let arc_elasticities =
+        Elasticity::get_observed_arc_elasticities(&prices, &quantity_purchase).unwrap();
+
+    assert_eq!(arc_elasticities.len(), 3);
+    assert!((arc_elasticities[0].elasticity + 3.0 / 7.0).abs() < 1e-5);
+    assert_eq!(arc_elasticities[0].class, ElasticityClass::Inelastic);
+    assert!((arc_elasticities[1].elasticity + 1.0).abs() < 1e-5);
+    assert_eq!(arc_elasticities[1].class, ElasticityClass::UnitElastic);
+    assert_eq!(arc_elasticities[2].class, ElasticityClass::Elastic);
+    assert_eq!(
+        Elasticity::get_arc_elasticity(20.0, 60.0, 10.0, 80.0),
+        Elasticity::get_arc_elasticity(10.0, 80.0, 20.0, 60.0)
+    );
+}
+
+#[test]
+fn it_splits_prices_into_elasticity_ranges() {
+    assert_eq!(Elasticity::get_unit_elastic_price(-2.0, 100.0), Some(25.0));
+
+    let elasticity_ranges = Elasticity::get_elasticity_ranges(-2.0, 100.0, 10.0, 40.0);
+    assert_eq!(
+        elasticity_ranges,
+        vec![
+            ElasticityRange {
+                start_price: 10.0,
+                end_price: 25.0,
+                class: ElasticityClass::Inelastic,
+            },
+            ElasticityRange {
+                start_price: 25.0,
+                end_price: 40.0,
+                class: ElasticityClass::Elastic,
+            },
+        ]
+    );
+
+    let elasticity_ranges = Elasticity::get_elasticity_ranges(-2.0, 100.0, 30.0, 40.0);
+    assert_eq!(elasticity_ranges.len(), 1);
+    assert_eq!(elasticity_ranges[0].class, ElasticityClass::Elastic);
+}
+
+#[test]
+fn it_returns_error_because_observations_are_insufficient() {
+    let result = Elasticity::get_observed_arc_elasticities(&[10.0], &[80.0]);
+
+    match result {
+        Ok(_) => panic!("Expected an error"),
+        Err(err) => assert_eq!(err.to_string(), "Insufficient lengths"),
+    }
+}
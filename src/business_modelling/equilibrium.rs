@@ -0,0 +1,132 @@
+use std::error::Error;
+
+use crate::business_modelling::linreg;
+
+// Demand and supply closer than this share of the quantities are taken as equal.
+const EQUILIBRIUM_TOLERANCE: f32 = 0.0001;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MarketCondition {
+    // More is supplied than demanded, by this quantity.
+    Surplus(f32),
+    Equilibrium,
+    // More is demanded than supplied, by this quantity.
+    Shortage(f32),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MarketEquilibrium {
+    pub price: f32,
+    pub quantity: f32,
+}
+
+// quantity = (m * price) + b for both curves, as fitted by Linreg
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Market {
+    pub demand_m: f32,
+    pub demand_b: f32,
+    pub supply_m: f32,
+    pub supply_b: f32,
+}
+
+impl Market {
+    pub fn new(demand_m: f32, demand_b: f32, supply_m: f32, supply_b: f32) -> Self {
+        Self {
+            demand_m,
+            demand_b,
+            supply_m,
+            supply_b,
+        }
+    }
+
+    pub fn from_observations(
+        prices: &[f32],
+        quantity_purchase: &[f32],
+        quantity_produce: &[f32],
+    ) -> Result<Self, Box<dyn Error>> {
+        let prices = prices.to_vec();
+        let (demand_m, demand_b) =
+            linreg::Linreg::linear_regress(&prices, &quantity_purchase.to_vec())?;
+        let (supply_m, supply_b) =
+            linreg::Linreg::linear_regress(&prices, &quantity_produce.to_vec())?;
+
+        Ok(Self::new(demand_m, demand_b, supply_m, supply_b))
+    }
+
+    pub fn get_demand(&self, price: f32) -> f32 {
+        (self.demand_m * price) + self.demand_b
+    }
+
+    pub fn get_supply(&self, price: f32) -> f32 {
+        (self.supply_m * price) + self.supply_b
+    }
+
+    // Price where the curves cross, None when they do not cross at a non-negative price and quantity
+    pub fn get_equilibrium(&self) -> Option<MarketEquilibrium> {
+        let slope_difference = self.demand_m - self.supply_m;
+        if slope_difference == 0.0 {
+            return None;
+        }
+
+        let price = (self.supply_b - self.demand_b) / slope_difference;
+        let quantity = self.get_demand(price);
+        if price < 0.0 || quantity < 0.0 {
+            return None;
+        }
+
+        Some(MarketEquilibrium { price, quantity })
+    }
+
+    pub fn get_market_condition(&self, price: f32) -> MarketCondition {
+        let demand = self.get_demand(price);
+        let supply = self.get_supply(price);
+        let tolerance = EQUILIBRIUM_TOLERANCE * demand.abs().max(supply.abs()).max(1.0);
+
+        if (supply - demand).abs() <= tolerance {
+            MarketCondition::Equilibrium
+        } else if supply > demand {
+            MarketCondition::Surplus(supply - demand)
+        } else {
+            MarketCondition::Shortage(demand - supply)
+        }
+    }
+
+    // Area between the demand curve and the equilibrium price, up to the price where demand runs out
+    pub fn get_consumer_surplus(&self) -> Option<f32> {
+        let equilibrium = self.get_equilibrium()?;
+        if self.demand_m >= 0.0 {
+            return None;
+        }
+
+        let choke_price = -self.demand_b / self.demand_m;
+        Some(get_area(
+            self.demand_m,
+            self.demand_b,
+            equilibrium.price,
+            choke_price,
+        ))
+    }
+
+    // Area between the equilibrium price and the supply curve, from the lowest price anything is supplied at
+    pub fn get_producer_surplus(&self) -> Option<f32> {
+        let equilibrium = self.get_equilibrium()?;
+        if self.supply_m <= 0.0 {
+            return None;
+        }
+
+        let reservation_price = (-self.supply_b / self.supply_m).max(0.0);
+        Some(get_area(
+            self.supply_m,
+            self.supply_b,
+            reservation_price,
+            equilibrium.price,
+        ))
+    }
+}
+
+// Integral of (m * price) + b from start_price to end_price
+pub(crate) fn get_area(m: f32, b: f32, start_price: f32, end_price: f32) -> f32 {
+    let antiderivative = |price: f32| (m * price.powi(2) / 2.0) + (b * price);
+
+    antiderivative(end_price) - antiderivative(start_price)
+}
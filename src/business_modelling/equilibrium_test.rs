@@ -0,0 +1,68 @@
+#[cfg(test)]
+use crate::business_modelling::equilibrium::*;
+
+// Demand is -2 * price + 100, supply 3 * price - 50
+#[cfg(test)]
+fn generate_market() -> Market {
+    Market::new(-2.0, 100.0, 3.0, -50.0)
+}
+
+#[test]
+fn it_solves_market_equilibrium() {
+    let equilibrium = generate_market().get_equilibrium().unwrap();
+
+    assert_eq!(equilibrium.price, 30.0);
+    assert_eq!(equilibrium.quantity, 40.0);
+}
+
+#[test]
+fn it_fits_market_from_observations() {
+    let prices = vec![10.0, 20.0, 30.0, 40.0];
+    let quantity_purchase = vec![80.0, 60.0, 40.0, 20.0];
+    let quantity_produce = vec![-20.0, 10.0, 40.0, 70.0];
+
+    let market = Market::from_observations(&prices, &quantity_purchase, &quantity_produce).unwrap();
+    let equilibrium = market.get_equilibrium().unwrap();
+
+    assert!((equilibrium.price - 30.0).abs() < 1e-4);
+    assert!((equilibrium.quantity - 40.0).abs() < 1e-4);
+}
+
+#[test]
+fn it_reports_shortage_and_surplus_at_a_price() {
+    let market = generate_market();
+
+    // Demand above supply below the equilibrium price
+    assert_eq!(
+        market.get_market_condition(20.0),
+        MarketCondition::Shortage(50.0)
+    );
+    assert_eq!(
+        market.get_market_condition(40.0),
+        MarketCondition::Surplus(50.0)
+    );
+    assert_eq!(
+        market.get_market_condition(30.0),
+        MarketCondition::Equilibrium
+    );
+    assert_eq!(
+        market.get_market_condition(30.000001),
+        MarketCondition::Equilibrium
+    );
+}
+
+#[test]
+fn it_computes_consumer_and_producer_surplus() {
+    let market = generate_market();
+
+    assert_eq!(market.get_consumer_surplus(), Some(400.0));
+    assert!((market.get_producer_surplus().unwrap() - 800.0 / 3.0).abs() < 1e-3);
+}
+
+#[test]
+fn it_returns_none_for_parallel_curves() {
+    let market = Market::new(-2.0, 100.0, -2.0, 50.0);
+
+    assert_eq!(market.get_equilibrium(), None);
+    assert_eq!(market.get_consumer_surplus(), None);
+}
@@ -0,0 +1,157 @@
+use crate::business_modelling::equilibrium::{get_area, Market, MarketCondition};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Intervention {
+    // Per unit, collected from sellers.
+    Tax(f32),
+    // Per unit, paid to sellers.
+    Subsidy(f32),
+    // Maximum legal price, binding below the equilibrium price.
+    PriceCeiling(f32),
+    // Minimum legal price, binding above the equilibrium price.
+    PriceFloor(f32),
+}
+
+// buyer_incidence and seller_incidence are the per unit shares of a tax (negative for a subsidy's benefit).
+// government_revenue is negative for the cost of a subsidy. The market condition is taken at the buyer price
+// against the shifted supply, so a tax alone still clears the market.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InterventionOutcome {
+    pub buyer_price: f32,
+    pub seller_price: f32,
+    pub quantity: f32,
+    pub buyer_incidence: f32,
+    pub seller_incidence: f32,
+    pub consumer_surplus: f32,
+    pub producer_surplus: f32,
+    pub government_revenue: f32,
+    pub deadweight_loss: f32,
+    pub market_condition: MarketCondition,
+}
+
+impl Market {
+    // Price controls ration the quantity traded to the short side of the market, served to the buyers and sellers
+    // who value it most. None when the market has no equilibrium to compare against.
+    pub fn apply_intervention(&self, intervention: Intervention) -> Option<InterventionOutcome> {
+        let equilibrium = self.get_equilibrium()?;
+        let choke_price = -self.demand_b / self.demand_m;
+        let reservation_price = (-self.supply_b / self.supply_m).max(0.0);
+        let welfare = self.get_consumer_surplus()? + self.get_producer_surplus()?;
+
+        let (buyer_price, seller_price, quantity, consumer_surplus, producer_surplus) =
+            match intervention {
+                Intervention::Tax(_) | Intervention::Subsidy(_) => {
+                    let tax = get_tax(intervention);
+                    // Demand at the buyer price meets supply at the buyer price less the tax
+                    let buyer_price = (self.supply_b - (self.supply_m * tax) - self.demand_b)
+                        / (self.demand_m - self.supply_m);
+                    // A prohibitive tax leaves no one buying at the choke price nor selling at the reservation price
+                    let (buyer_price, seller_price) = if self.get_demand(buyer_price) > 0.0 {
+                        (buyer_price, buyer_price - tax)
+                    } else {
+                        (choke_price, reservation_price)
+                    };
+
+                    (
+                        buyer_price,
+                        seller_price,
+                        self.get_demand(buyer_price).max(0.0),
+                        get_area(
+                            self.demand_m,
+                            self.demand_b,
+                            buyer_price,
+                            choke_price.max(buyer_price),
+                        ),
+                        get_area(
+                            self.supply_m,
+                            self.supply_b,
+                            reservation_price,
+                            seller_price.max(reservation_price),
+                        ),
+                    )
+                }
+                Intervention::PriceCeiling(ceiling) if ceiling < equilibrium.price => {
+                    let quantity = self.get_supply(ceiling).max(0.0);
+                    // Price at which buyers would want only the rationed quantity
+                    let rationed_price = (quantity - self.demand_b) / self.demand_m;
+
+                    (
+                        ceiling,
+                        ceiling,
+                        quantity,
+                        get_area(self.demand_m, self.demand_b, rationed_price, choke_price)
+                            + ((rationed_price - ceiling) * quantity),
+                        get_area(
+                            self.supply_m,
+                            self.supply_b,
+                            reservation_price,
+                            ceiling.max(reservation_price),
+                        ),
+                    )
+                }
+                Intervention::PriceFloor(floor) if floor > equilibrium.price => {
+                    let quantity = self.get_demand(floor).max(0.0);
+                    // Price at which sellers would offer only the rationed quantity
+                    let rationed_price = ((quantity - self.supply_b) / self.supply_m).max(0.0);
+
+                    (
+                        floor,
+                        floor,
+                        quantity,
+                        get_area(self.demand_m, self.demand_b, floor, choke_price.max(floor)),
+                        get_area(
+                            self.supply_m,
+                            self.supply_b,
+                            reservation_price,
+                            rationed_price.max(reservation_price),
+                        ) + ((floor - rationed_price) * quantity),
+                    )
+                }
+                // Controls that do not bind leave the market at equilibrium
+                Intervention::PriceCeiling(_) | Intervention::PriceFloor(_) => (
+                    equilibrium.price,
+                    equilibrium.price,
+                    equilibrium.quantity,
+                    self.get_consumer_surplus()?,
+                    self.get_producer_surplus()?,
+                ),
+            };
+
+        let government_revenue = (buyer_price - seller_price) * quantity;
+
+        Some(InterventionOutcome {
+            buyer_price,
+            seller_price,
+            quantity,
+            buyer_incidence: buyer_price - equilibrium.price,
+            seller_incidence: equilibrium.price - seller_price,
+            consumer_surplus,
+            producer_surplus,
+            government_revenue,
+            deadweight_loss: welfare - (consumer_surplus + producer_surplus + government_revenue),
+            market_condition: self
+                .get_shifted_supply(intervention)
+                .get_market_condition(buyer_price),
+        })
+    }
+
+    // Supply as seen at the buyer price once the intervention applies, a tax shifts it up by the tax
+    pub fn get_shifted_supply(&self, intervention: Intervention) -> Market {
+        let tax = get_tax(intervention);
+
+        Market::new(
+            self.demand_m,
+            self.demand_b,
+            self.supply_m,
+            self.supply_b - (self.supply_m * tax),
+        )
+    }
+}
+
+fn get_tax(intervention: Intervention) -> f32 {
+    match intervention {
+        Intervention::Tax(tax) => tax,
+        Intervention::Subsidy(subsidy) => -subsidy,
+        Intervention::PriceCeiling(_) | Intervention::PriceFloor(_) => 0.0,
+    }
+}
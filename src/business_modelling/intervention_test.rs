@@ -0,0 +1,87 @@
+#[cfg(test)]
+use crate::business_modelling::{assert_close, equilibrium::*, intervention::*};
+
+// Demand is -2 * price + 100, supply 3 * price - 50, equilibrium at price 30 and quantity 40
+#[cfg(test)]
+fn generate_market() -> Market {
+    Market::new(-2.0, 100.0, 3.0, -50.0)
+}
+
+#[test]
+fn it_splits_a_tax_between_buyers_and_sellers() {
+    let outcome = generate_market()
+        .apply_intervention(Intervention::Tax(5.0))
+        .unwrap();
+
+    assert_close(outcome.buyer_price, 33.0);
+    assert_close(outcome.seller_price, 28.0);
+    assert_close(outcome.quantity, 34.0);
+    assert_close(outcome.buyer_incidence, 3.0);
+    assert_close(outcome.seller_incidence, 2.0);
+    assert_close(outcome.government_revenue, 170.0);
+    assert_close(outcome.deadweight_loss, 15.0);
+    assert_eq!(outcome.market_condition, MarketCondition::Equilibrium);
+}
+
+#[test]
+fn it_stops_all_trade_under_a_prohibitive_tax() {
+    let outcome = generate_market()
+        .apply_intervention(Intervention::Tax(60.0))
+        .unwrap();
+
+    assert_close(outcome.quantity, 0.0);
+    assert_close(outcome.buyer_price, 50.0);
+    assert_close(outcome.seller_price, 50.0 / 3.0);
+    assert_close(outcome.consumer_surplus, 0.0);
+    assert_close(outcome.producer_surplus, 0.0);
+    assert_close(outcome.government_revenue, 0.0);
+    assert_close(outcome.deadweight_loss, 2000.0 / 3.0);
+}
+
+#[test]
+fn it_costs_the_government_to_subsidize() {
+    let outcome = generate_market()
+        .apply_intervention(Intervention::Subsidy(5.0))
+        .unwrap();
+
+    assert_close(outcome.buyer_price, 27.0);
+    assert_close(outcome.seller_price, 32.0);
+    assert_close(outcome.quantity, 46.0);
+    assert_close(outcome.government_revenue, -230.0);
+    assert_close(outcome.deadweight_loss, 15.0);
+}
+
+#[test]
+fn it_rations_quantity_under_a_binding_price_ceiling() {
+    let outcome = generate_market()
+        .apply_intervention(Intervention::PriceCeiling(25.0))
+        .unwrap();
+
+    assert_close(outcome.quantity, 25.0);
+    assert_eq!(outcome.market_condition, MarketCondition::Shortage(25.0));
+    assert_close(outcome.consumer_surplus, 468.75);
+    assert_close(outcome.deadweight_loss, 93.75);
+}
+
+#[test]
+fn it_leaves_a_surplus_under_a_binding_price_floor() {
+    let outcome = generate_market()
+        .apply_intervention(Intervention::PriceFloor(35.0))
+        .unwrap();
+
+    assert_close(outcome.quantity, 30.0);
+    assert_eq!(outcome.market_condition, MarketCondition::Surplus(25.0));
+    assert_close(outcome.producer_surplus, 400.0);
+    assert_close(outcome.deadweight_loss, 125.0 / 3.0);
+}
+
+#[test]
+fn it_ignores_a_price_control_that_does_not_bind() {
+    let outcome = generate_market()
+        .apply_intervention(Intervention::PriceCeiling(35.0))
+        .unwrap();
+
+    assert_close(outcome.buyer_price, 30.0);
+    assert_close(outcome.quantity, 40.0);
+    assert_close(outcome.deadweight_loss, 0.0);
+}
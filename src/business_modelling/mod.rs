@@ -1,4 +1,30 @@
-mod business_modelling;
+pub mod break_even;
+#[cfg(test)]
+mod break_even_test;
+pub mod business_modelling;
 mod business_modelling_test;
-mod linreg;
+pub mod cost_model;
+#[cfg(test)]
+mod cost_model_test;
+pub mod elasticity;
+#[cfg(test)]
+mod elasticity_test;
+pub mod equilibrium;
+#[cfg(test)]
+mod equilibrium_test;
+pub mod intervention;
+#[cfg(test)]
+mod intervention_test;
+pub mod linreg;
 mod linreg_test;
+
+// f32 results are compared within 1e-3 of the expected value, relative to it once it is larger than 1.
+#[cfg(test)]
+pub(crate) fn assert_close(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() <= 1e-3 * expected.abs().max(1.0),
+        "{} is not close to {}",
+        actual,
+        expected
+    );
+}
@@ -1,2 +1,3 @@
-mod business_modelling;
-mod stock_market;
+pub mod business_modelling;
+pub mod stock_market;
+pub mod time_value_of_money;
@@ -0,0 +1,417 @@
+use std::error::Error;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use crate::stock_market::{
+    order_book::{OrderBook, OrderRequest, OrderSide},
+    risk::{RiskMetrics, TRADING_DAYS_PER_YEAR},
+    stock_market::{StockData, StockInformation},
+    stock_portfolio::StockPortfolio,
+    transaction_ledger::{Fee, Transaction, TransactionKind, TransactionLedger},
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Signal {
+    // Invests all available cash in whole shares.
+    Buy,
+    BuyShares(Decimal),
+    // Sells every share held.
+    Sell,
+    SellShares(Decimal),
+    // Places an order on the simulated order book, see OrderBook for the fill rules.
+    Submit(OrderRequest),
+    Hold,
+}
+
+// What a strategy can see at the close of a bar, it never sees the bars after idx.
+pub struct BarContext<'a> {
+    pub idx: usize,
+    // Bars up to and including idx.
+    bars: &'a [StockData],
+    pub shares_held: Decimal,
+    pub cash: Decimal,
+}
+
+impl<'a> BarContext<'a> {
+    pub fn get_bar(&self) -> &'a StockData {
+        &self.bars[self.idx]
+    }
+
+    pub fn get_bars(&self) -> &'a [StockData] {
+        self.bars
+    }
+
+    // Simple moving average of the last ma_days closing prices ending at this bar.
+    pub fn get_moving_average(&self, ma_days: u16) -> Option<Decimal> {
+        let ma_days = ma_days as usize;
+        if ma_days == 0 || ma_days > self.idx + 1 {
+            return None;
+        }
+
+        let sum = self.get_bars()[self.idx + 1 - ma_days..]
+            .iter()
+            .fold(dec!(0), |acc, stock_data| acc + stock_data.get_close());
+        Some((sum / Decimal::from(ma_days)).round_dp(2))
+    }
+}
+
+pub trait Strategy {
+    fn on_bar(&mut self, context: &BarContext) -> Signal;
+}
+
+// Buys when the short SMA crosses above the long SMA and sells when it crosses below.
+#[derive(Debug)]
+pub struct MovingAverageCrossover {
+    short_ma_days: u16,
+    long_ma_days: u16,
+    // (short, long) moving averages of the previous bar.
+    previous_averages: Option<(Decimal, Decimal)>,
+}
+
+impl MovingAverageCrossover {
+    pub fn new(short_ma_days: u16, long_ma_days: u16) -> Self {
+        Self {
+            short_ma_days,
+            long_ma_days,
+            previous_averages: None,
+        }
+    }
+}
+
+impl Strategy for MovingAverageCrossover {
+    fn on_bar(&mut self, context: &BarContext) -> Signal {
+        if context.idx == 0 {
+            self.previous_averages = None;
+        }
+
+        let averages = context
+            .get_moving_average(self.short_ma_days)
+            .zip(context.get_moving_average(self.long_ma_days));
+        let previous_averages = std::mem::replace(&mut self.previous_averages, averages);
+
+        match (previous_averages, averages) {
+            (Some((previous_short, previous_long)), Some((short, long))) => {
+                if previous_short <= previous_long && short > long && context.shares_held == dec!(0)
+                {
+                    Signal::Buy
+                } else if previous_short >= previous_long
+                    && short < long
+                    && context.shares_held > dec!(0)
+                {
+                    Signal::Sell
+                } else {
+                    Signal::Hold
+                }
+            }
+            _ => Signal::Hold,
+        }
+    }
+}
+
+// Closed round trip, profit is net of commissions and taxes on both sides.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trade {
+    pub entry_date: DateTime<Utc>,
+    pub entry_price: Decimal,
+    pub exit_date: DateTime<Utc>,
+    pub exit_price: Decimal,
+    pub shares: Decimal,
+    pub profit: Decimal,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EquityPoint {
+    pub date: DateTime<Utc>,
+    pub equity: Decimal,
+}
+
+// Returns and drawdown are expressed in percent.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BacktestSummary {
+    pub initial_equity: Decimal,
+    pub final_equity: Decimal,
+    pub total_return: Decimal,
+    pub number_of_trades: usize,
+    pub winning_trades: usize,
+    pub win_rate: Decimal,
+    pub total_fees: Decimal,
+    pub max_drawdown: Decimal,
+    pub sharpe_ratio: Option<Decimal>,
+}
+
+#[derive(Debug)]
+pub struct BacktestResult {
+    pub trades: Vec<Trade>,
+    pub equity_curve: Vec<EquityPoint>,
+    pub summary: BacktestSummary,
+    pub portfolio: StockPortfolio,
+}
+
+// Signals raised at the close of a bar are filled at the open of the next bar,
+// moved against the trader by slippage_percent. Signals raised on the last bar are never filled.
+#[derive(Debug)]
+pub struct Backtester {
+    pub initial_cash: Decimal,
+    pub slippage_percent: Decimal,
+    pub commissions: Vec<Fee>,
+}
+
+impl Backtester {
+    pub fn new(initial_cash: Decimal, slippage_percent: Decimal, commissions: Vec<Fee>) -> Self {
+        Self {
+            initial_cash,
+            slippage_percent,
+            commissions,
+        }
+    }
+
+    pub fn run(
+        &self,
+        stock_information: &StockInformation,
+        strategy: &mut dyn Strategy,
+    ) -> Result<BacktestResult, Box<dyn Error>> {
+        // Bars are back-adjusted for corporate actions, so shares are counted as they are after the latest action.
+        let stock_data_series = stock_information.get_adjusted_stock_data_series();
+        if stock_data_series.is_empty() {
+            Err("Insufficient stock data series length")?;
+        }
+
+        let symbol = stock_information.get_symbol();
+        let mut portfolio = StockPortfolio::new(TransactionLedger::new());
+        portfolio.record(Transaction::new(
+            stock_data_series[0].get_date(),
+            TransactionKind::Deposit {
+                amount: self.initial_cash,
+            },
+            vec![],
+        ))?;
+
+        let mut trades: Vec<Trade> = vec![];
+        let mut equity_curve: Vec<EquityPoint> = Vec::with_capacity(stock_data_series.len());
+        let mut pending_signal = Signal::Hold;
+        let mut order_book = OrderBook::new(self.commissions.clone(), self.slippage_percent);
+
+        for (idx, stock_data) in stock_data_series.iter().enumerate() {
+            if pending_signal != Signal::Hold {
+                if let Some(trade) =
+                    self.fill(&mut portfolio, symbol, stock_data, &pending_signal)?
+                {
+                    trades.push(trade);
+                }
+            }
+
+            let fills = order_book.match_bar(
+                symbol,
+                stock_data,
+                portfolio.get_cash_balance(),
+                portfolio.get_shares_held(symbol),
+            );
+            for fill in fills {
+                // The order book already checked cash and shares, so the fill is recorded at its own size.
+                if let Some(trade) = self.record_fill(
+                    &mut portfolio,
+                    symbol,
+                    stock_data,
+                    fill.side,
+                    fill.shares,
+                    fill.price,
+                )? {
+                    trades.push(trade);
+                }
+            }
+
+            let shares_held = portfolio.get_shares_held(symbol);
+            let cash = portfolio.get_cash_balance();
+
+            equity_curve.push(EquityPoint {
+                date: stock_data.get_date(),
+                equity: cash + shares_held * stock_data.get_close(),
+            });
+
+            pending_signal = strategy.on_bar(&BarContext {
+                idx,
+                bars: &stock_data_series[..=idx],
+                shares_held,
+                cash,
+            });
+
+            if let Signal::Submit(request) = pending_signal {
+                order_book.submit(symbol, stock_data.get_date(), request)?;
+                pending_signal = Signal::Hold;
+            }
+        }
+
+        let summary = self.get_summary(&portfolio, &trades, &equity_curve);
+
+        Ok(BacktestResult {
+            trades,
+            equity_curve,
+            summary,
+            portfolio,
+        })
+    }
+
+    fn fill(
+        &self,
+        portfolio: &mut StockPortfolio,
+        symbol: &str,
+        stock_data: &StockData,
+        signal: &Signal,
+    ) -> Result<Option<Trade>, Box<dyn Error>> {
+        let slippage = self.slippage_percent / dec!(100);
+
+        match signal {
+            Signal::Buy | Signal::BuyShares(_) => {
+                let price = stock_data.get_open() * (dec!(1) + slippage);
+                // Buys are capped at what the cash covers, the balance never goes negative.
+                let affordable_shares =
+                    self.get_affordable_shares(portfolio.get_cash_balance(), price);
+                let shares = match signal {
+                    Signal::BuyShares(shares) => (*shares).min(affordable_shares),
+                    _ => affordable_shares,
+                };
+                self.record_fill(portfolio, symbol, stock_data, OrderSide::Buy, shares, price)
+            }
+            Signal::Sell | Signal::SellShares(_) => {
+                let price = stock_data.get_open() * (dec!(1) - slippage);
+                let shares_held = portfolio.get_shares_held(symbol);
+                let shares = match signal {
+                    Signal::SellShares(shares) => (*shares).min(shares_held),
+                    _ => shares_held,
+                };
+                self.record_fill(
+                    portfolio,
+                    symbol,
+                    stock_data,
+                    OrderSide::Sell,
+                    shares,
+                    price,
+                )
+            }
+            _ => Ok(None),
+        }
+    }
+
+    // Records shares that are already known to be affordable, either capped by fill or matched by the order book.
+    fn record_fill(
+        &self,
+        portfolio: &mut StockPortfolio,
+        symbol: &str,
+        stock_data: &StockData,
+        side: OrderSide,
+        shares: Decimal,
+        price: Decimal,
+    ) -> Result<Option<Trade>, Box<dyn Error>> {
+        if shares <= dec!(0) {
+            return Ok(None);
+        }
+
+        match side {
+            OrderSide::Buy => {
+                portfolio.record(Transaction::new(
+                    stock_data.get_date(),
+                    TransactionKind::Buy {
+                        symbol: symbol.to_string(),
+                        shares,
+                        price,
+                    },
+                    self.commissions.clone(),
+                ))?;
+                Ok(None)
+            }
+            OrderSide::Sell => {
+                let open_lots = portfolio.get_open_lots(symbol);
+                let mut remaining_shares = shares;
+                let mut cost_basis = dec!(0);
+                for lot in open_lots.iter() {
+                    let sold_shares = remaining_shares.min(lot.shares);
+                    cost_basis += sold_shares * lot.cost_per_share;
+                    remaining_shares -= sold_shares;
+                }
+                let net_gains = portfolio.get_net_gains(symbol);
+
+                portfolio.record(Transaction::new(
+                    stock_data.get_date(),
+                    TransactionKind::Sell {
+                        symbol: symbol.to_string(),
+                        shares,
+                        price,
+                    },
+                    self.commissions.clone(),
+                ))?;
+
+                Ok(Some(Trade {
+                    entry_date: open_lots[0].date,
+                    entry_price: cost_basis / shares,
+                    exit_date: stock_data.get_date(),
+                    exit_price: price,
+                    shares,
+                    profit: portfolio.get_net_gains(symbol) - net_gains,
+                }))
+            }
+        }
+    }
+
+    fn get_affordable_shares(&self, cash: Decimal, price: Decimal) -> Decimal {
+        if price <= dec!(0) || cash <= dec!(0) {
+            return dec!(0);
+        }
+
+        let mut shares = (cash / price).floor();
+        while shares > dec!(0) {
+            let gross_amount = shares * price;
+            let fees = self.commissions.iter().fold(dec!(0), |acc, fee| {
+                acc + fee.get_amount(shares, gross_amount, false)
+            });
+            if gross_amount + fees <= cash {
+                break;
+            }
+            shares -= dec!(1);
+        }
+        shares
+    }
+
+    fn get_summary(
+        &self,
+        portfolio: &StockPortfolio,
+        trades: &[Trade],
+        equity_curve: &[EquityPoint],
+    ) -> BacktestSummary {
+        let final_equity = equity_curve
+            .last()
+            .map(|point| point.equity)
+            .unwrap_or(self.initial_cash);
+        let equities = equity_curve
+            .iter()
+            .map(|point| point.equity)
+            .collect::<Vec<Decimal>>();
+        let winning_trades = trades.iter().filter(|trade| trade.profit > dec!(0)).count();
+
+        BacktestSummary {
+            initial_equity: self.initial_cash,
+            final_equity,
+            total_return: if self.initial_cash > dec!(0) {
+                (((final_equity - self.initial_cash) / self.initial_cash) * dec!(100)).round_dp(2)
+            } else {
+                dec!(0)
+            },
+            number_of_trades: trades.len(),
+            winning_trades,
+            win_rate: if trades.is_empty() {
+                dec!(0)
+            } else {
+                ((Decimal::from(winning_trades) / Decimal::from(trades.len())) * dec!(100))
+                    .round_dp(2)
+            },
+            total_fees: portfolio.ledger.get_total_fees(),
+            max_drawdown: RiskMetrics::get_max_drawdown(&equities)
+                .map(|drawdown| drawdown.max_drawdown.round_dp(2))
+                .unwrap_or_default(),
+            sharpe_ratio: RiskMetrics::get_returns(&equities).and_then(|returns| {
+                RiskMetrics::get_sharpe_ratio(&returns, dec!(0), TRADING_DAYS_PER_YEAR)
+            }),
+        }
+    }
+}
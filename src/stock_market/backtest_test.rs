@@ -0,0 +1,182 @@
+#[cfg(test)]
+use crate::stock_market::backtest::*;
+use crate::stock_market::generate_utc_date_from_date_string;
+use crate::stock_market::{
+    stock_market::{StockData, StockInformation},
+    transaction_ledger::Fee,
+};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+// (open, close) per day
+fn generate_stock_information(prices: Vec<(Decimal, Decimal)>) -> StockInformation {
+    let stock_data_series = prices
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (open, close))| {
+            StockData::new(
+                generate_utc_date_from_date_string(&format!("10-{:02}-2022 00:00", idx + 1)),
+                open.max(close),
+                open.min(close),
+                open,
+                close,
+            )
+        })
+        .collect();
+    StockInformation::new(
+        "BenCorpo".to_string(),
+        "BNCRP".to_string(),
+        stock_data_series,
+    )
+}
+
+struct ScheduledStrategy {
+    signals: Vec<Signal>,
+}
+
+impl Strategy for ScheduledStrategy {
+    fn on_bar(&mut self, context: &BarContext) -> Signal {
+        self.signals
+            .get(context.idx)
+            .cloned()
+            .unwrap_or(Signal::Hold)
+    }
+}
+
+#[test]
+fn it_fills_signals_at_next_open_with_slippage_and_commissions() {
+    let stock_information = generate_stock_information(vec![
+        (dec!(10), dec!(10)),
+        (dec!(11), dec!(12)),
+        (dec!(12), dec!(13)),
+        (dec!(14), dec!(15)),
+    ]);
+    let mut strategy = ScheduledStrategy {
+        signals: vec![Signal::Buy, Signal::Hold, Signal::Sell],
+    };
+    let backtester = Backtester::new(dec!(1000), dec!(1), vec![Fee::Fixed(dec!(1))]);
+
+    let result = backtester.run(&stock_information, &mut strategy).unwrap();
+
+    assert_eq!(result.trades.len(), 1);
+    assert_eq!(result.trades[0].shares, dec!(89));
+    assert_eq!(result.trades[0].exit_price, dec!(13.86));
+    assert_eq!(result.trades[0].profit, dec!(242.75));
+    assert_eq!(result.equity_curve.len(), 4);
+    assert_eq!(result.equity_curve[1].equity, dec!(1078.21));
+    assert_eq!(result.summary.final_equity, dec!(1242.75));
+    assert_eq!(result.summary.total_return, dec!(24.28));
+    assert_eq!(result.summary.win_rate, dec!(100));
+    assert_eq!(result.summary.total_fees, dec!(2));
+}
+
+#[test]
+fn it_ignores_signals_raised_on_the_last_bar() {
+    let stock_information =
+        generate_stock_information(vec![(dec!(10), dec!(10)), (dec!(11), dec!(12))]);
+    let mut strategy = ScheduledStrategy {
+        signals: vec![Signal::Hold, Signal::Buy],
+    };
+    let backtester = Backtester::new(dec!(1000), dec!(0), vec![]);
+
+    let result = backtester.run(&stock_information, &mut strategy).unwrap();
+
+    assert_eq!(result.summary.final_equity, dec!(1000));
+    assert_eq!(result.portfolio.get_shares_held("BNCRP"), dec!(0));
+}
+
+#[test]
+fn it_caps_share_buys_at_available_cash() {
+    let stock_information = generate_stock_information(vec![
+        (dec!(10), dec!(10)),
+        (dec!(10), dec!(10)),
+        (dec!(10), dec!(10)),
+    ]);
+    let mut strategy = ScheduledStrategy {
+        signals: vec![Signal::BuyShares(dec!(500))],
+    };
+    let backtester = Backtester::new(dec!(1000), dec!(0), vec![Fee::Fixed(dec!(5))]);
+
+    let result = backtester.run(&stock_information, &mut strategy).unwrap();
+
+    assert_eq!(result.portfolio.get_shares_held("BNCRP"), dec!(99));
+    assert_eq!(result.portfolio.get_cash_balance(), dec!(5));
+    assert_eq!(result.summary.final_equity, dec!(995));
+}
+
+struct LookaheadStrategy {
+    visible_bars: Vec<usize>,
+}
+
+impl Strategy for LookaheadStrategy {
+    fn on_bar(&mut self, context: &BarContext) -> Signal {
+        self.visible_bars.push(context.get_bars().len());
+        assert_eq!(
+            context.get_bar().get_date(),
+            context.get_bars().last().unwrap().get_date()
+        );
+        Signal::Hold
+    }
+}
+
+#[test]
+fn it_only_shows_bars_up_to_the_current_one() {
+    let stock_information = generate_stock_information(vec![
+        (dec!(10), dec!(10)),
+        (dec!(11), dec!(12)),
+        (dec!(12), dec!(13)),
+    ]);
+    let mut strategy = LookaheadStrategy {
+        visible_bars: vec![],
+    };
+    let backtester = Backtester::new(dec!(1000), dec!(0), vec![]);
+
+    backtester.run(&stock_information, &mut strategy).unwrap();
+
+    assert_eq!(strategy.visible_bars, vec![1, 2, 3]);
+}
+
+#[test]
+fn it_backtests_moving_average_crossover() {
+    let closing_prices = vec![
+        dec!(10),
+        dec!(9),
+        dec!(8),
+        dec!(9),
+        dec!(11),
+        dec!(13),
+        dec!(12),
+        dec!(10),
+        dec!(8),
+        dec!(7),
+    ];
+    let stock_information = generate_stock_information(
+        closing_prices
+            .iter()
+            .map(|close| (*close, *close))
+            .collect(),
+    );
+    let mut strategy = MovingAverageCrossover::new(2, 3);
+    let backtester = Backtester::new(dec!(1000), dec!(0), vec![]);
+
+    let result = backtester.run(&stock_information, &mut strategy).unwrap();
+
+    assert_eq!(result.trades.len(), 1);
+    assert!(result.trades[0].entry_date < result.trades[0].exit_date);
+    assert_eq!(result.summary.number_of_trades, 1);
+}
+
+#[test]
+fn it_returns_error_because_stock_data_series_is_empty() {
+    let stock_information =
+        StockInformation::new("BenCorpo".to_string(), "BNCRP".to_string(), vec![]);
+    let mut strategy = MovingAverageCrossover::new(2, 3);
+    let backtester = Backtester::new(dec!(1000), dec!(0), vec![]);
+
+    let result = backtester.run(&stock_information, &mut strategy);
+
+    match result {
+        Ok(_) => println!("Success"),
+        Err(err) => assert_eq!(err.to_string(), "Insufficient stock data series length"),
+    }
+}
@@ -1,50 +1,123 @@
-use std::collections::HashMap;
+use std::error::Error;
 
-#[derive(Clone, Debug)]
-pub enum HashMapValue {
-    Str(String),
-    Float32(f32),
+use chrono::{DateTime, Utc};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use rust_decimal_macros::dec;
+
+use crate::stock_market::{
+    cap_table::{CapTable, DilutionReport, ShareClass},
+    corporate_dividend::DividendLedger,
+    equity_event::EquityEvent,
+    partnership_distribution::AllocationStatement,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnershipStake {
+    pub name: String,
+    pub investment: Decimal,
+    pub percentage: Decimal,
+    pub shares: u64,
 }
 
-impl HashMapValue {
-    pub fn str(&self) -> &String {
-        match self {
-            HashMapValue::Str(v) => v,
-            _ => panic!("Not HashMapValue::Str"),
-        }
-    }
-    pub fn f32(&self) -> &f32 {
-        match self {
-            HashMapValue::Float32(v) => v,
-            _ => panic!("Not HashMapValue::Str"),
-        }
-    }
+// exact_shares is the owner's unrounded part of total_shares, remainder its fractional part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShareAllocation {
+    pub name: String,
+    pub exact_shares: Decimal,
+    pub shares: u64,
+    pub remainder: Decimal,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Owner {
     name: String,
-    investment: f32,
+    investment: Decimal,
 }
 
 impl Owner {
-    pub fn new(name: String, investment: f32) -> Self {
+    pub fn new(name: String, investment: Decimal) -> Self {
         Self { name, investment }
     }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn get_investment(&self) -> Decimal {
+        self.investment
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Liability {
+    Unlimited,
+    // The owner can lose at most the amount invested.
+    LimitedToInvestment(Decimal),
+}
+
+// A negative amount is a share of a loss.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProfitAllocation {
+    pub name: String,
+    pub amount: Decimal,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AllocationRule {
+    // In proportion to each owner's investment.
+    CapitalRatio,
+    // Stated ratios per owner name, e.g. 3:2.
+    FixedRatio(Vec<(String, Decimal)>),
+    // Salaries first, then interest_rate percent on each owner's investment, the rest (or the shortfall when
+    // the income does not cover them) split by residual_ratios.
+    SalaryPlusInterest {
+        salaries: Vec<(String, Decimal)>,
+        interest_rate: Decimal,
+        residual_ratios: Vec<(String, Decimal)>,
+    },
+}
+
+impl AllocationRule {
+    pub fn allocate(
+        &self,
+        owners: &[Owner],
+        net_income: Decimal,
+    ) -> Result<Vec<ProfitAllocation>, Box<dyn Error>> {
+        Ok(self
+            .get_allocation_statement(owners, net_income, &[])?
+            .allocations
+            .into_iter()
+            .map(|allocation| ProfitAllocation {
+                name: allocation.name,
+                amount: allocation.total,
+            })
+            .collect())
+    }
+}
+
+pub trait BusinessOrganization {
+    fn get_name(&self) -> &str;
+    fn get_owners(&self) -> Vec<&Owner>;
+    fn get_liability(&self, owner_name: &str) -> Option<Liability>;
+    fn allocate_profit(&self, net_income: Decimal)
+        -> Result<Vec<ProfitAllocation>, Box<dyn Error>>;
 }
 
 #[derive(Debug)]
 pub struct Corporation {
     pub name: String,
+    // Owners and total_investment at founding, see get_owners_as_of for later equity events.
     pub owners: Vec<Owner>,
-    pub total_investment: f32,
+    pub total_investment: Decimal,
     pub total_shares: u64, // max_val 18_446_744_073_709_551_615,
+    pub cap_table: CapTable,
+    pub dividends: DividendLedger,
+    equity_events: Vec<EquityEvent>,
 }
 
 impl Corporation {
     pub fn new(owners: Vec<Owner>, name: Option<String>, total_shares: Option<u64>) -> Self {
         let total_investment = Self::get_total_investments(&owners);
-        Self {
+        let mut corporation = Self {
             name: match name {
                 Some(name) => name,
                 None => "".to_string(),
@@ -55,92 +128,487 @@ impl Corporation {
                 Some(total_shares) => total_shares,
                 None => 0,
             },
+            cap_table: CapTable::new(),
+            dividends: DividendLedger::new(),
+            equity_events: vec![],
+        };
+
+        // Owners start with common shares bought with their investment.
+        for (owner, allocation) in corporation
+            .owners
+            .iter()
+            .zip(corporation.get_share_allocations())
+        {
+            if allocation.shares == 0 {
+                continue;
+            }
+
+            corporation.cap_table.issue_shares(
+                &owner.name,
+                ShareClass::Common,
+                allocation.shares,
+                owner.investment / Decimal::from(allocation.shares),
+            );
+        }
+
+        corporation
+    }
+
+    pub fn get_equity_events(&self) -> &Vec<EquityEvent> {
+        &self.equity_events
+    }
+
+    // Events are kept ordered by date. The whole history is replayed first, an event that does not fit it
+    // (unknown owner, withdrawing more than was invested, admitting an existing owner) is not recorded.
+    pub fn record_equity_event(&mut self, event: EquityEvent) -> Result<(), Box<dyn Error>> {
+        let mut equity_events = self.equity_events.clone();
+        let idx = equity_events.partition_point(|existing| existing.date <= event.date);
+        equity_events.insert(idx, event);
+
+        let mut owners = self.owners.clone();
+        for equity_event in equity_events.iter() {
+            equity_event.apply(&mut owners)?;
+        }
+
+        self.equity_events = equity_events;
+        Ok(())
+    }
+
+    // Owners after every equity event up to and including date.
+    pub fn get_owners_as_of(&self, date: DateTime<Utc>) -> Vec<Owner> {
+        let mut owners = self.owners.clone();
+        for equity_event in self
+            .equity_events
+            .iter()
+            .take_while(|equity_event| equity_event.date <= date)
+        {
+            // Recorded events were validated against the whole history.
+            let _ = equity_event.apply(&mut owners);
+        }
+        owners
+    }
+
+    pub fn get_total_investment_as_of(&self, date: DateTime<Utc>) -> Decimal {
+        Self::get_total_investments(&self.get_owners_as_of(date))
+    }
+
+    // Percentages and shares are recomputed from the owners' capital at date over the same total_shares.
+    pub fn get_ownership_stakes_as_of(&self, date: DateTime<Utc>) -> Vec<OwnershipStake> {
+        Corporation::new(
+            self.get_owners_as_of(date),
+            Some(self.name.clone()),
+            Some(self.total_shares),
+        )
+        .get_owners_ownership_percentages()
+    }
+
+    // How each owner was diluted by a funding round recorded in the cap table.
+    pub fn get_owner_dilution_reports(&self, round_name: &str) -> Vec<DilutionReport> {
+        match self.cap_table.get_round(round_name) {
+            Some(round) => round
+                .dilution_reports
+                .iter()
+                .filter(|report| self.owners.iter().any(|owner| owner.name == report.holder))
+                .cloned()
+                .collect(),
+            None => vec![],
         }
     }
 
-    pub fn get_total_investments(owners: &Vec<Owner>) -> f32 {
-        owners.iter().fold(0.0, |acc, owner| acc + owner.investment)
+    pub fn get_total_investments(owners: &Vec<Owner>) -> Decimal {
+        owners
+            .iter()
+            .fold(dec!(0), |acc, owner| acc + owner.investment)
     }
 
-    pub fn get_owner_ownership_percentage_by_investment(&self, owner_name: &str) -> f32 {
+    pub fn get_owner_ownership_percentage_by_investment(&self, owner_name: &str) -> Decimal {
         let found_owner = self.owners.iter().find(|owner| owner.name == owner_name);
 
         match found_owner {
-            Some(found_owner) => ((found_owner.investment / self.total_investment) * 100.0).round(),
-            None => 0.0,
+            Some(found_owner) if self.total_investment > dec!(0) => {
+                (found_owner.investment / self.total_investment) * dec!(100)
+            }
+            _ => dec!(0),
         }
     }
 
-    pub fn get_owners_ownership_percentages(&self) -> Vec<HashMap<String, HashMapValue>> {
-        let owners_ownership_percentages = self
+    // Largest remainder method: every owner gets the whole part of their exact shares, the shares left over go one
+    // each to the largest fractional remainders (earlier owners first on ties), so allocations sum to total_shares.
+    pub fn get_share_allocations(&self) -> Vec<ShareAllocation> {
+        let mut allocations = self
             .owners
             .iter()
             .map(|owner| {
-                let mut owner_hashmap: HashMap<String, HashMapValue> = HashMap::new();
-                owner_hashmap.insert("name".to_string(), HashMapValue::Str(owner.name.clone()));
-                owner_hashmap.insert(
-                    "percentage".to_string(),
-                    HashMapValue::Float32(
-                        (owner.investment / self.total_investment * 100.0).round(),
-                    ),
-                );
-
-                owner_hashmap
+                let exact_shares = if self.total_investment > dec!(0) {
+                    Decimal::from(self.total_shares) * owner.investment / self.total_investment
+                } else {
+                    dec!(0)
+                };
+
+                ShareAllocation {
+                    name: owner.name.clone(),
+                    exact_shares,
+                    shares: exact_shares.floor().to_u64().unwrap_or_default(),
+                    remainder: exact_shares.fract(),
+                }
             })
-            .collect::<Vec<HashMap<String, HashMapValue>>>();
-        owners_ownership_percentages
+            .collect::<Vec<ShareAllocation>>();
+
+        let allocated_shares = allocations
+            .iter()
+            .fold(0, |acc, allocation| acc + allocation.shares);
+        let leftover_shares = if self.total_investment > dec!(0) {
+            self.total_shares.saturating_sub(allocated_shares) as usize
+        } else {
+            0
+        };
+
+        let mut idxs = (0..allocations.len()).collect::<Vec<usize>>();
+        idxs.sort_by(|a, b| allocations[*b].remainder.cmp(&allocations[*a].remainder));
+        for idx in idxs.into_iter().take(leftover_shares) {
+            allocations[idx].shares += 1;
+        }
+
+        allocations
+    }
+
+    pub fn get_owners_ownership_percentages(&self) -> Vec<OwnershipStake> {
+        self.owners
+            .iter()
+            .zip(self.get_share_allocations())
+            .map(|(owner, allocation)| OwnershipStake {
+                name: owner.name.clone(),
+                investment: owner.investment,
+                percentage: self.get_owner_ownership_percentage_by_investment(&owner.name),
+                shares: allocation.shares,
+            })
+            .collect()
     }
 
     pub fn get_owner_shares_by_ownership_percentage(&self, owner_name: &str) -> u64 {
-        let ownership_percentage =
-            self.get_owner_ownership_percentage_by_investment(owner_name) as u64;
-        (ownership_percentage * self.total_shares) / 100
+        self.get_share_allocations()
+            .into_iter()
+            .find(|allocation| allocation.name == owner_name)
+            .map(|allocation| allocation.shares)
+            .unwrap_or_default()
     }
 
+    // Owners with the largest investment, more than one when they are tied.
+    pub fn get_largest_shareholders(&self) -> Vec<OwnershipStake> {
+        let ownership_stakes = self.get_owners_ownership_percentages();
+        let largest_investment = ownership_stakes
+            .iter()
+            .map(|stake| stake.investment)
+            .max()
+            .unwrap_or_default();
+
+        ownership_stakes
+            .into_iter()
+            .filter(|stake| stake.investment == largest_investment)
+            .collect()
+    }
+
+    // Only true for the single largest shareholder, tied owners and corporations without owners have none.
     pub fn is_owner_majority_shareholder(&self, owner_name: &str) -> bool {
-        let owners_ownership_percentages = self.get_owners_ownership_percentages();
-        let mut major_shareholder: HashMap<String, HashMapValue> = HashMap::new();
+        match self.get_largest_shareholders().as_slice() {
+            [largest_shareholder] => largest_shareholder.name == owner_name,
+            _ => false,
+        }
+    }
+}
+
+impl BusinessOrganization for Corporation {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+    fn get_owners(&self) -> Vec<&Owner> {
+        self.owners.iter().collect()
+    }
+    fn get_liability(&self, owner_name: &str) -> Option<Liability> {
+        self.owners
+            .iter()
+            .find(|owner| owner.name == owner_name)
+            .map(|owner| Liability::LimitedToInvestment(owner.investment))
+    }
+    // Shareholders share in proportion to their investment.
+    fn allocate_profit(
+        &self,
+        net_income: Decimal,
+    ) -> Result<Vec<ProfitAllocation>, Box<dyn Error>> {
+        AllocationRule::CapitalRatio.allocate(&self.owners, net_income)
+    }
+}
 
-        let first_owner = &owners_ownership_percentages[0];
+#[derive(Debug)]
+pub struct SoleProprietorship {
+    pub name: String,
+    pub owner: Owner,
+}
+
+impl SoleProprietorship {
+    pub fn new(owner: Owner, name: Option<String>) -> Self {
+        Self {
+            name: name.unwrap_or_default(),
+            owner,
+        }
+    }
+}
 
-        println!("{:?}", first_owner);
-        for key in first_owner.keys() {
-            let key = key.to_string();
+impl BusinessOrganization for SoleProprietorship {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+    fn get_owners(&self) -> Vec<&Owner> {
+        vec![&self.owner]
+    }
+    fn get_liability(&self, owner_name: &str) -> Option<Liability> {
+        if self.owner.name == owner_name {
+            Some(Liability::Unlimited)
+        } else {
+            None
+        }
+    }
+    fn allocate_profit(
+        &self,
+        net_income: Decimal,
+    ) -> Result<Vec<ProfitAllocation>, Box<dyn Error>> {
+        Ok(vec![ProfitAllocation {
+            name: self.owner.name.clone(),
+            amount: net_income,
+        }])
+    }
+}
 
-            let value_enum = first_owner.get(&key).clone().unwrap();
+#[derive(Debug)]
+pub struct GeneralPartnership {
+    pub name: String,
+    pub partners: Vec<Owner>,
+    pub allocation_rule: AllocationRule,
+}
 
-            match value_enum {
-                HashMapValue::Str(value_enum) => {
-                    major_shareholder.insert(key, HashMapValue::Str(value_enum.to_string()))
-                }
-                HashMapValue::Float32(value_enum) => {
-                    major_shareholder.insert(key, HashMapValue::Float32(*value_enum))
-                }
-            };
+impl GeneralPartnership {
+    pub fn new(
+        partners: Vec<Owner>,
+        allocation_rule: AllocationRule,
+        name: Option<String>,
+    ) -> Self {
+        Self {
+            name: name.unwrap_or_default(),
+            partners,
+            allocation_rule,
+        }
+    }
+
+    pub fn get_allocation_statement(
+        &self,
+        net_income: Decimal,
+        drawings: &[(String, Decimal)],
+    ) -> Result<AllocationStatement, Box<dyn Error>> {
+        self.allocation_rule
+            .get_allocation_statement(&self.partners, net_income, drawings)
+    }
+}
+
+impl BusinessOrganization for GeneralPartnership {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+    fn get_owners(&self) -> Vec<&Owner> {
+        self.partners.iter().collect()
+    }
+    fn get_liability(&self, owner_name: &str) -> Option<Liability> {
+        self.partners
+            .iter()
+            .find(|partner| partner.name == owner_name)
+            .map(|_| Liability::Unlimited)
+    }
+    fn allocate_profit(
+        &self,
+        net_income: Decimal,
+    ) -> Result<Vec<ProfitAllocation>, Box<dyn Error>> {
+        self.allocation_rule.allocate(&self.partners, net_income)
+    }
+}
+
+#[derive(Debug)]
+pub struct LimitedPartnership {
+    pub name: String,
+    pub general_partners: Vec<Owner>,
+    pub limited_partners: Vec<Owner>,
+    pub allocation_rule: AllocationRule,
+}
+
+impl LimitedPartnership {
+    pub fn new(
+        general_partners: Vec<Owner>,
+        limited_partners: Vec<Owner>,
+        allocation_rule: AllocationRule,
+        name: Option<String>,
+    ) -> Result<Self, Box<dyn Error>> {
+        if general_partners.is_empty() {
+            Err("A limited partnership needs at least one general partner")?;
+        }
+
+        Ok(Self {
+            name: name.unwrap_or_default(),
+            general_partners,
+            limited_partners,
+            allocation_rule,
+        })
+    }
+}
+
+impl BusinessOrganization for LimitedPartnership {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+    fn get_owners(&self) -> Vec<&Owner> {
+        self.general_partners
+            .iter()
+            .chain(self.limited_partners.iter())
+            .collect()
+    }
+    fn get_liability(&self, owner_name: &str) -> Option<Liability> {
+        if self
+            .general_partners
+            .iter()
+            .any(|partner| partner.name == owner_name)
+        {
+            return Some(Liability::Unlimited);
+        }
+        self.limited_partners
+            .iter()
+            .find(|partner| partner.name == owner_name)
+            .map(|partner| Liability::LimitedToInvestment(partner.investment))
+    }
+    // Limited partners absorb losses only up to their investment, the excess moves to the general partners
+    // in proportion to their investment, or equally when none of them invested.
+    fn allocate_profit(
+        &self,
+        net_income: Decimal,
+    ) -> Result<Vec<ProfitAllocation>, Box<dyn Error>> {
+        let partners = self
+            .general_partners
+            .iter()
+            .chain(self.limited_partners.iter())
+            .cloned()
+            .collect::<Vec<Owner>>();
+        let mut allocations = self.allocation_rule.allocate(&partners, net_income)?;
+
+        let general_partners_len = self.general_partners.len();
+        let mut excess_loss = dec!(0);
+        for (allocation, partner) in allocations[general_partners_len..]
+            .iter_mut()
+            .zip(self.limited_partners.iter())
+        {
+            if allocation.amount < -partner.investment {
+                excess_loss += allocation.amount + partner.investment;
+                allocation.amount = -partner.investment;
+            }
         }
 
-        let major_shareholder =
-            owners_ownership_percentages
+        if excess_loss != dec!(0) {
+            let has_investment = self
+                .general_partners
+                .iter()
+                .any(|partner| partner.investment > dec!(0));
+            let general_partner_ratios = self
+                .general_partners
                 .iter()
-                .fold(major_shareholder, |mut acc, owner| {
-                    let current_major_shareholder_percentage = acc.get("percentage").unwrap().f32();
-                    let owner_percentage = owner.get("percentage").unwrap().f32();
-                    let o_name = owner.get("name").unwrap().str();
-
-                    if current_major_shareholder_percentage < owner_percentage {
-                        acc.insert("name".to_string(), HashMapValue::Str(o_name.to_string()));
-                        acc.insert(
-                            "percentage".to_string(),
-                            HashMapValue::Float32(owner_percentage.clone()),
-                        );
-                        return acc;
+                .map(|partner| {
+                    let ratio = if has_investment {
+                        partner.investment
                     } else {
-                        return acc;
-                    }
-                });
+                        dec!(1)
+                    };
+                    (partner.name.clone(), ratio)
+                })
+                .collect::<Vec<(String, Decimal)>>();
+            let excess_amounts =
+                split_by_ratios(&self.general_partners, &general_partner_ratios, excess_loss)?;
 
-        let major_share_holder_name = major_shareholder.get("name").unwrap().str();
+            for (allocation, excess_amount) in allocations.iter_mut().zip(excess_amounts) {
+                allocation.amount += excess_amount;
+            }
+        }
 
-        major_share_holder_name == owner_name
+        Ok(allocations)
     }
 }
+
+#[derive(Debug)]
+pub struct LimitedLiabilityCompany {
+    pub name: String,
+    pub members: Vec<Owner>,
+    pub allocation_rule: AllocationRule,
+}
+
+impl LimitedLiabilityCompany {
+    pub fn new(members: Vec<Owner>, allocation_rule: AllocationRule, name: Option<String>) -> Self {
+        Self {
+            name: name.unwrap_or_default(),
+            members,
+            allocation_rule,
+        }
+    }
+
+    pub fn get_allocation_statement(
+        &self,
+        net_income: Decimal,
+        drawings: &[(String, Decimal)],
+    ) -> Result<AllocationStatement, Box<dyn Error>> {
+        self.allocation_rule
+            .get_allocation_statement(&self.members, net_income, drawings)
+    }
+}
+
+impl BusinessOrganization for LimitedLiabilityCompany {
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+    fn get_owners(&self) -> Vec<&Owner> {
+        self.members.iter().collect()
+    }
+    fn get_liability(&self, owner_name: &str) -> Option<Liability> {
+        self.members
+            .iter()
+            .find(|member| member.name == owner_name)
+            .map(|member| Liability::LimitedToInvestment(member.investment))
+    }
+    fn allocate_profit(
+        &self,
+        net_income: Decimal,
+    ) -> Result<Vec<ProfitAllocation>, Box<dyn Error>> {
+        self.allocation_rule.allocate(&self.members, net_income)
+    }
+}
+
+pub(crate) fn get_named_amount(ratios: &[(String, Decimal)], name: &str) -> Decimal {
+    ratios
+        .iter()
+        .find(|(ratio_name, _)| ratio_name == name)
+        .map(|(_, ratio)| *ratio)
+        .unwrap_or_default()
+}
+
+// Owners missing from the ratios get nothing, the ratios have to add up to more than zero.
+pub(crate) fn split_by_ratios(
+    owners: &[Owner],
+    ratios: &[(String, Decimal)],
+    amount: Decimal,
+) -> Result<Vec<Decimal>, Box<dyn Error>> {
+    let owner_ratios = owners
+        .iter()
+        .map(|owner| get_named_amount(ratios, &owner.name))
+        .collect::<Vec<Decimal>>();
+    let total_ratio = owner_ratios.iter().fold(dec!(0), |acc, ratio| acc + ratio);
+
+    if total_ratio <= dec!(0) {
+        Err("Allocation ratios must add up to more than zero")?;
+    }
+
+    Ok(owner_ratios
+        .into_iter()
+        .map(|ratio| amount * ratio / total_ratio)
+        .collect())
+}
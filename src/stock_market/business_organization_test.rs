@@ -1,11 +1,15 @@
 #[cfg(test)]
 use crate::stock_market::business_organization::*;
+#[cfg(test)]
+use rust_decimal::Decimal;
+#[cfg(test)]
+use rust_decimal_macros::dec;
 
 #[test]
 fn it_creates_a_new_organization_with_owners() {
-    let mark = Owner::new("Mark".to_string(), 250.0);
-    let benedict = Owner::new("Benedict".to_string(), 200.0);
-    let ben = Owner::new("Ben".to_string(), 150.25);
+    let mark = Owner::new("Mark".to_string(), dec!(250));
+    let benedict = Owner::new("Benedict".to_string(), dec!(200));
+    let ben = Owner::new("Ben".to_string(), dec!(150.25));
 
     let owners = vec![mark, benedict, ben];
     let organization = Corporation::new(owners, Some("KamoteCorp".to_string()), Some(100_000));
@@ -15,94 +19,88 @@ fn it_creates_a_new_organization_with_owners() {
 
 #[test]
 fn it_creates_a_new_organization_and_computes_total_investment() {
-    let mark = Owner::new("Mark".to_string(), 250.0);
-    let benedict = Owner::new("Benedict".to_string(), 200.0);
-    let ben = Owner::new("Ben".to_string(), 150.25);
+    let mark = Owner::new("Mark".to_string(), dec!(250));
+    let benedict = Owner::new("Benedict".to_string(), dec!(200));
+    let ben = Owner::new("Ben".to_string(), dec!(150.25));
 
     let owners = vec![mark, benedict, ben];
     let organization = Corporation::new(owners, None, Some(100_000));
 
-    assert_eq!(organization.total_investment, 250.0 + 200.0 + 150.25);
+    assert_eq!(organization.total_investment, dec!(600.25));
 }
 
 #[test]
 fn it_creates_a_new_organization_and_gets_ownership_percentage_of_owner() {
-    let mark = Owner::new("Mark".to_string(), 250.0);
-    let benedict = Owner::new("Benedict".to_string(), 200.0);
-    let ben = Owner::new("Ben".to_string(), 150.25);
+    let mark = Owner::new("Mark".to_string(), dec!(250));
+    let benedict = Owner::new("Benedict".to_string(), dec!(200));
+    let ben = Owner::new("Ben".to_string(), dec!(150.25));
 
     let owners = vec![mark, benedict, ben];
     let organization = Corporation::new(owners, None, Some(100_000));
 
     let ownership_percentage = organization.get_owner_ownership_percentage_by_investment("Mark");
 
-    assert_eq!(ownership_percentage, 42.0);
+    assert_eq!(ownership_percentage.round_dp(4), dec!(41.6493));
 }
 
 #[test]
 fn it_creates_a_new_organization_and_tries_to_get_ownership_percentage_of_unregistered_owner() {
-    let mark = Owner::new("Mark".to_string(), 250.0);
-    let benedict = Owner::new("Benedict".to_string(), 200.0);
-    let ben = Owner::new("Ben".to_string(), 150.25);
+    let mark = Owner::new("Mark".to_string(), dec!(250));
+    let benedict = Owner::new("Benedict".to_string(), dec!(200));
+    let ben = Owner::new("Ben".to_string(), dec!(150.25));
 
     let owners = vec![mark, benedict, ben];
     let organization = Corporation::new(owners, None, Some(100_000));
 
     let ownership_percentage = organization.get_owner_ownership_percentage_by_investment("MEMA");
 
-    assert_eq!(ownership_percentage, 0.0);
+    assert_eq!(ownership_percentage, dec!(0));
 }
 
 #[test]
 fn it_creates_a_new_organization_and_gets_owner_shares_by_ownership_percentage() {
-    let mark = Owner::new("Mark".to_string(), 250.0);
-    let benedict = Owner::new("Benedict".to_string(), 200.0);
-    let ben = Owner::new("Ben".to_string(), 150.25);
+    let mark = Owner::new("Mark".to_string(), dec!(250));
+    let benedict = Owner::new("Benedict".to_string(), dec!(200));
+    let ben = Owner::new("Ben".to_string(), dec!(150.25));
 
     let owners = vec![mark, benedict, ben];
     let organization = Corporation::new(owners, None, Some(100_000));
 
     let owner_shares = organization.get_owner_shares_by_ownership_percentage("Mark");
 
-    assert_eq!(owner_shares, 42000);
+    assert_eq!(owner_shares, 41649);
 }
 
 #[test]
 fn it_creates_a_new_organization_and_gets_owner_ownership_percentages() {
-    let mark = Owner::new("Mark".to_string(), 250.0);
-    let benedict = Owner::new("Benedict".to_string(), 200.0);
-    let ben = Owner::new("Ben".to_string(), 150.25);
+    let mark = Owner::new("Mark".to_string(), dec!(250));
+    let benedict = Owner::new("Benedict".to_string(), dec!(200));
+    let ben = Owner::new("Ben".to_string(), dec!(150.25));
 
     let owners = vec![mark, benedict, ben];
     let organization = Corporation::new(owners, None, Some(100_000));
 
     let owner_ownership_percentages = organization.get_owners_ownership_percentages();
 
-    let mark = owner_ownership_percentages[0]
-        .get("percentage")
-        .unwrap()
-        .f32();
-    let benedict = owner_ownership_percentages[1]
-        .get("percentage")
-        .unwrap()
-        .f32();
-    let ben = owner_ownership_percentages[2]
-        .get("percentage")
-        .unwrap()
-        .f32();
+    let mark = &owner_ownership_percentages[0];
+    let benedict = &owner_ownership_percentages[1];
+    let ben = &owner_ownership_percentages[2];
 
     assert_eq!(owner_ownership_percentages.len(), 3);
-    assert_eq!(mark, &42.0);
-    assert_eq!(benedict, &33.0);
-    assert_eq!(ben, &25.0);
+    assert_eq!(mark.name, "Mark");
+    assert_eq!(mark.percentage.round_dp(2), dec!(41.65));
+    assert_eq!(mark.shares, 41649);
+    assert_eq!(benedict.percentage.round_dp(2), dec!(33.32));
+    assert_eq!(ben.percentage.round_dp(2), dec!(25.03));
+    assert_eq!(ben.investment, dec!(150.25));
 }
 
 #[test]
 fn it_creates_a_new_organization_and_detects_major_and_not_major_shareholder() {
-    let mark = Owner::new("Mark".to_string(), 250.0);
-    let benedict = Owner::new("Benedict".to_string(), 200.0);
-    let ben = Owner::new("Ben".to_string(), 150.25);
-    let hello = Owner::new("Hello".to_string(), 500.25);
+    let mark = Owner::new("Mark".to_string(), dec!(250));
+    let benedict = Owner::new("Benedict".to_string(), dec!(200));
+    let ben = Owner::new("Ben".to_string(), dec!(150.25));
+    let hello = Owner::new("Hello".to_string(), dec!(500.25));
 
     let owners = vec![mark, benedict, ben, hello];
 
@@ -130,3 +128,262 @@ fn it_creates_a_new_organization_and_detects_major_and_not_major_shareholder() {
 
     println!("Organization {:?}", organization);
 }
+
+#[test]
+fn it_creates_a_new_organization_and_reports_owner_dilution() {
+    let mark = Owner::new("Mark".to_string(), dec!(250));
+    let benedict = Owner::new("Benedict".to_string(), dec!(200));
+    let ben = Owner::new("Ben".to_string(), dec!(150.25));
+
+    let owners = vec![mark, benedict, ben];
+    let mut organization = Corporation::new(owners, None, Some(100_000));
+
+    assert_eq!(organization.cap_table.get_issued_shares(), 100_000);
+
+    organization
+        .cap_table
+        .raise_round(
+            "Series A",
+            dec!(1_000_000),
+            vec![("Fund".to_string(), dec!(250_000))],
+            None,
+        )
+        .unwrap();
+
+    let dilution_reports = organization.get_owner_dilution_reports("Series A");
+
+    assert_eq!(dilution_reports.len(), 3);
+    assert_eq!(dilution_reports[0].holder, "Mark");
+    assert_eq!(dilution_reports[0].percent_after, dec!(33.3192));
+    assert_eq!(dilution_reports[0].dilution.round_dp(4), dec!(8.3298));
+    assert!(organization.get_owner_dilution_reports("Seed").is_empty());
+}
+
+#[test]
+fn it_creates_a_new_organization_and_detects_no_major_shareholder_on_tie() {
+    let mark = Owner::new("Mark".to_string(), dec!(250));
+    let benedict = Owner::new("Benedict".to_string(), dec!(250));
+    let ben = Owner::new("Ben".to_string(), dec!(150.25));
+
+    let owners = vec![mark, benedict, ben];
+    let organization = Corporation::new(owners, None, Some(100_000));
+
+    let largest_shareholders = organization
+        .get_largest_shareholders()
+        .iter()
+        .map(|stake| stake.name.clone())
+        .collect::<Vec<String>>();
+
+    assert_eq!(largest_shareholders, vec!["Mark", "Benedict"]);
+    assert!(!organization.is_owner_majority_shareholder("Mark"));
+    assert!(!organization.is_owner_majority_shareholder("Benedict"));
+}
+
+#[test]
+fn it_creates_a_new_organization_without_owners() {
+    let organization = Corporation::new(vec![], None, Some(100_000));
+
+    assert!(organization.get_owners_ownership_percentages().is_empty());
+    assert!(organization.get_largest_shareholders().is_empty());
+    assert!(!organization.is_owner_majority_shareholder("Mark"));
+}
+
+#[test]
+fn it_creates_a_new_organization_and_allocates_all_shares_by_largest_remainder() {
+    let mark = Owner::new("Mark".to_string(), dec!(250));
+    let benedict = Owner::new("Benedict".to_string(), dec!(200));
+    let ben = Owner::new("Ben".to_string(), dec!(150.25));
+
+    let owners = vec![mark, benedict, ben];
+    let organization = Corporation::new(owners, None, Some(100_000));
+
+    let share_allocations = organization.get_share_allocations();
+    let shares = share_allocations
+        .iter()
+        .map(|allocation| allocation.shares)
+        .collect::<Vec<u64>>();
+    let remainders = share_allocations
+        .iter()
+        .map(|allocation| allocation.remainder.round_dp(4))
+        .collect::<Vec<Decimal>>();
+
+    assert_eq!(shares, vec![41649, 33320, 25031]);
+    assert_eq!(shares.iter().sum::<u64>(), 100_000);
+    assert_eq!(remainders, vec![dec!(0.3128), dec!(0.4502), dec!(0.2370)]);
+    assert_eq!(organization.cap_table.get_issued_shares(), 100_000);
+}
+
+#[cfg(test)]
+fn get_amounts(allocations: Vec<ProfitAllocation>) -> Vec<Decimal> {
+    allocations
+        .into_iter()
+        .map(|allocation| allocation.amount)
+        .collect()
+}
+
+#[test]
+fn it_creates_a_sole_proprietorship_and_allocates_all_profit_to_owner() {
+    let mark = Owner::new("Mark".to_string(), dec!(10000));
+    let organization = SoleProprietorship::new(mark, Some("Mark's Bakery".to_string()));
+
+    let allocations = organization.allocate_profit(dec!(-2500)).unwrap();
+
+    assert_eq!(organization.get_name(), "Mark's Bakery");
+    assert_eq!(get_amounts(allocations), vec![dec!(-2500)]);
+    assert_eq!(
+        organization.get_liability("Mark"),
+        Some(Liability::Unlimited)
+    );
+    assert_eq!(organization.get_liability("Ben"), None);
+}
+
+#[test]
+fn it_creates_a_general_partnership_and_allocates_by_capital_and_fixed_ratio() {
+    let partners = vec![
+        Owner::new("Mark".to_string(), dec!(60000)),
+        Owner::new("Ben".to_string(), dec!(40000)),
+    ];
+    let capital_ratio_partnership =
+        GeneralPartnership::new(partners.clone(), AllocationRule::CapitalRatio, None);
+    let fixed_ratio_partnership = GeneralPartnership::new(
+        partners.clone(),
+        AllocationRule::FixedRatio(vec![
+            ("Mark".to_string(), dec!(1)),
+            ("Ben".to_string(), dec!(3)),
+        ]),
+        None,
+    );
+    let unknown_ratio_partnership = GeneralPartnership::new(
+        partners,
+        AllocationRule::FixedRatio(vec![("Wala".to_string(), dec!(1))]),
+        None,
+    );
+
+    assert_eq!(
+        get_amounts(
+            capital_ratio_partnership
+                .allocate_profit(dec!(10000))
+                .unwrap()
+        ),
+        vec![dec!(6000), dec!(4000)]
+    );
+    assert_eq!(
+        get_amounts(
+            fixed_ratio_partnership
+                .allocate_profit(dec!(10000))
+                .unwrap()
+        ),
+        vec![dec!(2500), dec!(7500)]
+    );
+    assert!(unknown_ratio_partnership
+        .allocate_profit(dec!(10000))
+        .is_err());
+    assert_eq!(
+        capital_ratio_partnership.get_liability("Ben"),
+        Some(Liability::Unlimited)
+    );
+}
+
+#[test]
+fn it_creates_a_general_partnership_and_allocates_salary_plus_interest() {
+    let partners = vec![
+        Owner::new("Mark".to_string(), dec!(60000)),
+        Owner::new("Ben".to_string(), dec!(40000)),
+    ];
+    let partnership = GeneralPartnership::new(
+        partners,
+        AllocationRule::SalaryPlusInterest {
+            salaries: vec![
+                ("Mark".to_string(), dec!(5000)),
+                ("Ben".to_string(), dec!(3000)),
+            ],
+            interest_rate: dec!(10),
+            residual_ratios: vec![("Mark".to_string(), dec!(1)), ("Ben".to_string(), dec!(1))],
+        },
+        None,
+    );
+
+    assert_eq!(
+        get_amounts(partnership.allocate_profit(dec!(30000)).unwrap()),
+        vec![dec!(17000), dec!(13000)]
+    );
+    assert_eq!(
+        get_amounts(partnership.allocate_profit(dec!(12000)).unwrap()),
+        vec![dec!(8000), dec!(4000)]
+    );
+}
+
+#[test]
+fn it_creates_a_limited_partnership_and_caps_limited_partner_losses() {
+    let partnership = LimitedPartnership::new(
+        vec![Owner::new("Mark".to_string(), dec!(10000))],
+        vec![Owner::new("Ben".to_string(), dec!(5000))],
+        AllocationRule::CapitalRatio,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(
+        get_amounts(partnership.allocate_profit(dec!(-30000)).unwrap()),
+        vec![dec!(-25000), dec!(-5000)]
+    );
+    assert_eq!(
+        get_amounts(partnership.allocate_profit(dec!(3000)).unwrap()),
+        vec![dec!(2000), dec!(1000)]
+    );
+    assert_eq!(
+        partnership.get_liability("Ben"),
+        Some(Liability::LimitedToInvestment(dec!(5000)))
+    );
+    assert_eq!(partnership.get_owners().len(), 2);
+    assert!(LimitedPartnership::new(vec![], vec![], AllocationRule::CapitalRatio, None).is_err());
+}
+
+#[test]
+fn it_splits_excess_losses_equally_among_general_partners_without_investment() {
+    let partnership = LimitedPartnership::new(
+        vec![
+            Owner::new("Mark".to_string(), dec!(0)),
+            Owner::new("Jane".to_string(), dec!(0)),
+        ],
+        vec![Owner::new("Ben".to_string(), dec!(5000))],
+        AllocationRule::CapitalRatio,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(
+        get_amounts(partnership.allocate_profit(dec!(-30000)).unwrap()),
+        vec![dec!(-12500), dec!(-12500), dec!(-5000)]
+    );
+}
+
+#[test]
+fn it_creates_a_limited_liability_company_and_corporation_allocations() {
+    let members = vec![
+        Owner::new("Mark".to_string(), dec!(60000)),
+        Owner::new("Ben".to_string(), dec!(40000)),
+    ];
+    let company = LimitedLiabilityCompany::new(
+        members.clone(),
+        AllocationRule::FixedRatio(vec![
+            ("Mark".to_string(), dec!(1)),
+            ("Ben".to_string(), dec!(1)),
+        ]),
+        None,
+    );
+    let corporation = Corporation::new(members, None, Some(100_000));
+
+    assert_eq!(
+        get_amounts(company.allocate_profit(dec!(10000)).unwrap()),
+        vec![dec!(5000), dec!(5000)]
+    );
+    assert_eq!(
+        company.get_liability("Mark"),
+        Some(Liability::LimitedToInvestment(dec!(60000)))
+    );
+    assert_eq!(
+        get_amounts(corporation.allocate_profit(dec!(10000)).unwrap()),
+        vec![dec!(6000), dec!(4000)]
+    );
+}
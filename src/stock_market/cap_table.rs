@@ -0,0 +1,293 @@
+use std::error::Error;
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ShareClass {
+    Common,
+    // Named series, e.g. "Series A". Preferred shares count one vote and one share as-converted.
+    Preferred(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShareIssuance {
+    pub holder: String,
+    pub share_class: ShareClass,
+    pub shares: u64,
+    pub price_per_share: Decimal,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct OptionGrant {
+    pub holder: String,
+    pub shares: u64,
+    pub strike_price: Decimal,
+}
+
+// Ownership is measured fully diluted: issued shares plus the whole option pool, granted or not.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DilutionReport {
+    pub holder: String,
+    pub shares: u64,
+    pub percent_before: Decimal,
+    pub percent_after: Decimal,
+    // Percentage points lost in the round.
+    pub dilution: Decimal,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FundingRound {
+    pub name: String,
+    pub pre_money_valuation: Decimal,
+    pub post_money_valuation: Decimal,
+    pub price_per_share: Decimal,
+    pub option_pool_increase: u64,
+    pub issuances: Vec<ShareIssuance>,
+    pub dilution_reports: Vec<DilutionReport>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CapTable {
+    issuances: Vec<ShareIssuance>,
+    option_pool: u64,
+    option_grants: Vec<OptionGrant>,
+    rounds: Vec<FundingRound>,
+}
+
+impl CapTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn issue_shares(
+        &mut self,
+        holder: &str,
+        share_class: ShareClass,
+        shares: u64,
+        price_per_share: Decimal,
+    ) {
+        self.issuances.push(ShareIssuance {
+            holder: holder.to_string(),
+            share_class,
+            shares,
+            price_per_share,
+        });
+    }
+
+    pub fn expand_option_pool(&mut self, shares: u64) {
+        self.option_pool += shares;
+    }
+
+    pub fn grant_options(
+        &mut self,
+        holder: &str,
+        shares: u64,
+        strike_price: Decimal,
+    ) -> Result<(), Box<dyn Error>> {
+        if shares > self.get_available_options() {
+            Err("Not enough options left in the option pool")?;
+        }
+
+        self.option_grants.push(OptionGrant {
+            holder: holder.to_string(),
+            shares,
+            strike_price,
+        });
+        Ok(())
+    }
+
+    pub fn get_issuances(&self) -> &Vec<ShareIssuance> {
+        &self.issuances
+    }
+
+    pub fn get_option_grants(&self) -> &Vec<OptionGrant> {
+        &self.option_grants
+    }
+
+    pub fn get_rounds(&self) -> &Vec<FundingRound> {
+        &self.rounds
+    }
+
+    pub fn get_round(&self, name: &str) -> Option<&FundingRound> {
+        self.rounds.iter().find(|round| round.name == name)
+    }
+
+    pub fn get_option_pool(&self) -> u64 {
+        self.option_pool
+    }
+
+    pub fn get_available_options(&self) -> u64 {
+        let granted = self
+            .option_grants
+            .iter()
+            .fold(0, |acc, grant| acc + grant.shares);
+        self.option_pool.saturating_sub(granted)
+    }
+
+    pub fn get_issued_shares(&self) -> u64 {
+        self.issuances
+            .iter()
+            .fold(0, |acc, issuance| acc + issuance.shares)
+    }
+
+    pub fn get_issued_shares_by_class(&self, share_class: &ShareClass) -> u64 {
+        self.issuances
+            .iter()
+            .filter(|issuance| &issuance.share_class == share_class)
+            .fold(0, |acc, issuance| acc + issuance.shares)
+    }
+
+    pub fn get_fully_diluted_shares(&self) -> u64 {
+        self.get_issued_shares() + self.option_pool
+    }
+
+    // Issued shares plus granted options of a holder.
+    pub fn get_holder_shares(&self, holder: &str) -> u64 {
+        let issued = self
+            .issuances
+            .iter()
+            .filter(|issuance| issuance.holder == holder)
+            .fold(0, |acc, issuance| acc + issuance.shares);
+        let granted = self
+            .option_grants
+            .iter()
+            .filter(|grant| grant.holder == holder)
+            .fold(0, |acc, grant| acc + grant.shares);
+
+        issued + granted
+    }
+
+    pub fn get_holders(&self) -> Vec<String> {
+        let mut holders: Vec<String> = vec![];
+        for holder in self
+            .issuances
+            .iter()
+            .map(|issuance| &issuance.holder)
+            .chain(self.option_grants.iter().map(|grant| &grant.holder))
+        {
+            if !holders.contains(holder) {
+                holders.push(holder.to_string());
+            }
+        }
+        holders
+    }
+
+    pub fn get_fully_diluted_percent(&self, holder: &str) -> Decimal {
+        let fully_diluted_shares = self.get_fully_diluted_shares();
+        if fully_diluted_shares == 0 {
+            return dec!(0);
+        }
+        Decimal::from(self.get_holder_shares(holder)) / Decimal::from(fully_diluted_shares)
+            * dec!(100)
+    }
+
+    // Prices a round on the pre-money fully diluted shares and issues preferred shares named after the round
+    // to the investors.
+    // With target_option_pool_percent, the pool is topped up before the round (and so diluting only the existing
+    // holders) until the unallocated options make up that percent of the post-money fully diluted shares.
+    pub fn raise_round(
+        &mut self,
+        name: &str,
+        pre_money_valuation: Decimal,
+        investments: Vec<(String, Decimal)>,
+        target_option_pool_percent: Option<Decimal>,
+    ) -> Result<FundingRound, Box<dyn Error>> {
+        if pre_money_valuation <= dec!(0) {
+            Err("Pre-money valuation must be positive")?;
+        }
+        if self.get_fully_diluted_shares() == 0 {
+            Err("Cap table has no shares to price the round on")?;
+        }
+        if self.get_round(name).is_some() {
+            Err("Funding round already exists")?;
+        }
+        if investments.iter().any(|(_, amount)| *amount <= dec!(0)) {
+            Err("Investment must be positive")?;
+        }
+
+        let holders_before = self
+            .get_holders()
+            .into_iter()
+            .map(|holder| {
+                let percent = self.get_fully_diluted_percent(&holder);
+                (holder, percent)
+            })
+            .collect::<Vec<(String, Decimal)>>();
+
+        let total_investment = investments
+            .iter()
+            .fold(dec!(0), |acc, (_, amount)| acc + amount);
+        let post_money_valuation = pre_money_valuation + total_investment;
+
+        let option_pool_increase = match target_option_pool_percent {
+            Some(target_option_pool_percent) => {
+                let target = target_option_pool_percent / dec!(100) * post_money_valuation
+                    / pre_money_valuation;
+                if target >= dec!(1) {
+                    Err("Target option pool is too large for the round")?;
+                }
+
+                let increase = (target * Decimal::from(self.get_fully_diluted_shares())
+                    - Decimal::from(self.get_available_options()))
+                    / (dec!(1) - target);
+                u64::try_from(increase.max(dec!(0)).ceil())?
+            }
+            None => 0,
+        };
+
+        // The issuances are built before the pool is expanded, so a failing round leaves the table untouched.
+        let price_per_share = pre_money_valuation
+            / (Decimal::from(self.get_fully_diluted_shares())
+                + Decimal::from(option_pool_increase));
+        let share_class = ShareClass::Preferred(name.to_string());
+
+        let issuances = investments
+            .iter()
+            .map(|(investor, amount)| {
+                Ok(ShareIssuance {
+                    holder: investor.to_string(),
+                    share_class: share_class.clone(),
+                    shares: u64::try_from((amount / price_per_share).floor())?,
+                    price_per_share,
+                })
+            })
+            .collect::<Result<Vec<ShareIssuance>, Box<dyn Error>>>()?;
+        self.expand_option_pool(option_pool_increase);
+        self.issuances.extend(issuances.clone());
+
+        let mut holders = holders_before.clone();
+        for issuance in issuances.iter() {
+            if !holders.iter().any(|(holder, _)| holder == &issuance.holder) {
+                holders.push((issuance.holder.to_string(), dec!(0)));
+            }
+        }
+
+        let dilution_reports = holders
+            .into_iter()
+            .map(|(holder, percent_before)| {
+                let percent_after = self.get_fully_diluted_percent(&holder);
+                DilutionReport {
+                    shares: self.get_holder_shares(&holder),
+                    holder,
+                    percent_before,
+                    percent_after,
+                    dilution: percent_before - percent_after,
+                }
+            })
+            .collect();
+
+        let funding_round = FundingRound {
+            name: name.to_string(),
+            pre_money_valuation,
+            post_money_valuation,
+            price_per_share,
+            option_pool_increase,
+            issuances,
+            dilution_reports,
+        };
+        self.rounds.push(funding_round.clone());
+
+        Ok(funding_round)
+    }
+}
@@ -0,0 +1,119 @@
+#[cfg(test)]
+use crate::stock_market::cap_table::*;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn generate_cap_table() -> CapTable {
+    let mut cap_table = CapTable::new();
+    cap_table.issue_shares("Mark", ShareClass::Common, 6_000_000, dec!(0.0001));
+    cap_table.issue_shares("Benedict", ShareClass::Common, 4_000_000, dec!(0.0001));
+    cap_table
+}
+
+#[test]
+fn it_issues_shares_and_grants_options() {
+    let mut cap_table = generate_cap_table();
+    cap_table.expand_option_pool(1_000_000);
+
+    assert!(cap_table.grant_options("Ben", 400_000, dec!(0.1)).is_ok());
+    assert!(cap_table.grant_options("Ben", 700_000, dec!(0.1)).is_err());
+    assert_eq!(cap_table.get_issued_shares(), 10_000_000);
+    assert_eq!(cap_table.get_fully_diluted_shares(), 11_000_000);
+    assert_eq!(cap_table.get_available_options(), 600_000);
+    assert_eq!(cap_table.get_holder_shares("Ben"), 400_000);
+    assert_eq!(cap_table.get_holders(), vec!["Mark", "Benedict", "Ben"]);
+}
+
+#[test]
+fn it_raises_round_and_reports_dilution() {
+    let mut cap_table = generate_cap_table();
+
+    let funding_round = cap_table
+        .raise_round(
+            "Series A",
+            dec!(8_000_000),
+            vec![("Fund".to_string(), dec!(2_000_000))],
+            None,
+        )
+        .unwrap();
+
+    assert_eq!(funding_round.post_money_valuation, dec!(10_000_000));
+    assert_eq!(funding_round.price_per_share, dec!(0.8));
+    assert_eq!(funding_round.issuances[0].shares, 2_500_000);
+    assert_eq!(
+        cap_table.get_issued_shares_by_class(&ShareClass::Preferred("Series A".to_string())),
+        2_500_000
+    );
+
+    let dilution = funding_round
+        .dilution_reports
+        .iter()
+        .map(|report| {
+            (
+                report.holder.as_str(),
+                report.percent_after,
+                report.dilution,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        dilution,
+        vec![
+            ("Mark", dec!(48), dec!(12)),
+            ("Benedict", dec!(32), dec!(8)),
+            ("Fund", dec!(20), dec!(-20)),
+        ]
+    );
+    assert!(cap_table
+        .raise_round("Series A", dec!(8_000_000), vec![], None)
+        .is_err());
+}
+
+#[test]
+fn it_tops_up_option_pool_before_round() {
+    let mut cap_table = generate_cap_table();
+
+    let funding_round = cap_table
+        .raise_round(
+            "Seed",
+            dec!(8_000_000),
+            vec![("Fund".to_string(), dec!(2_000_000))],
+            Some(dec!(10)),
+        )
+        .unwrap();
+
+    let option_pool_percent = (Decimal::from(cap_table.get_available_options())
+        / Decimal::from(cap_table.get_fully_diluted_shares())
+        * dec!(100))
+    .round_dp(2);
+
+    assert_eq!(funding_round.option_pool_increase, 1_428_572);
+    assert_eq!(funding_round.issuances[0].shares, 2_857_143);
+    assert_eq!(option_pool_percent, dec!(10));
+    assert_eq!(
+        cap_table.get_fully_diluted_percent("Fund").round_dp(2),
+        dec!(20)
+    );
+}
+
+#[test]
+fn it_leaves_cap_table_untouched_when_round_fails() {
+    let mut cap_table = generate_cap_table();
+
+    let funding_round = cap_table.raise_round(
+        "Seed",
+        dec!(8_000_000),
+        vec![
+            ("Fund".to_string(), dec!(2_000_000)),
+            ("Angel".to_string(), dec!(-100_000)),
+        ],
+        Some(dec!(10)),
+    );
+
+    assert!(funding_round.is_err());
+    assert_eq!(cap_table.get_available_options(), 0);
+    assert_eq!(cap_table.get_fully_diluted_shares(), 10_000_000);
+    assert_eq!(cap_table.get_issuances().len(), 2);
+    assert!(cap_table.get_round("Seed").is_none());
+}
@@ -0,0 +1,255 @@
+use std::error::Error;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use crate::stock_market::{
+    stock_market::{StockData, StockInformation},
+    stock_portfolio::StockPortfolio,
+    transaction_ledger::{Transaction, TransactionKind},
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CorporateActionKind {
+    // future_stock for held_stock (e.g. 2 for 1), same arguments as get_post_split_data.
+    Split {
+        future_stock: Decimal,
+        held_stock: Decimal,
+    },
+    // future_stock for held_stock where fewer shares are received (e.g. 1 for 10).
+    ReverseSplit {
+        future_stock: Decimal,
+        held_stock: Decimal,
+    },
+    // cost_allocation_percent is also taken as the share of the parent's value that left with the spun-off company.
+    SpinOff {
+        new_symbol: String,
+        new_shares_per_share: Decimal,
+        cost_allocation_percent: Decimal,
+    },
+}
+
+// date is the effective date, bars from that date on already trade on the adjusted basis.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CorporateAction {
+    pub date: DateTime<Utc>,
+    pub kind: CorporateActionKind,
+}
+
+impl CorporateAction {
+    pub fn new(date: DateTime<Utc>, kind: CorporateActionKind) -> Result<Self, Box<dyn Error>> {
+        match &kind {
+            CorporateActionKind::Split {
+                future_stock,
+                held_stock,
+            } => {
+                if *held_stock <= dec!(0) || future_stock <= held_stock {
+                    Err("Split must give more shares than are held")?;
+                }
+            }
+            CorporateActionKind::ReverseSplit {
+                future_stock,
+                held_stock,
+            } => {
+                if *future_stock <= dec!(0) || future_stock >= held_stock {
+                    Err("Reverse split must give fewer shares than are held")?;
+                }
+            }
+            CorporateActionKind::SpinOff {
+                new_shares_per_share,
+                cost_allocation_percent,
+                ..
+            } => {
+                if *new_shares_per_share < dec!(0) {
+                    Err("Spun-off shares per share cannot be negative")?;
+                }
+                if *cost_allocation_percent < dec!(0) || *cost_allocation_percent >= dec!(100) {
+                    Err("Cost allocation must be from 0% up to less than 100%")?;
+                }
+            }
+        }
+
+        Ok(Self { date, kind })
+    }
+
+    // Multiplier that makes prices before the action comparable with prices after it.
+    pub fn get_price_adjustment_factor(&self) -> Decimal {
+        match &self.kind {
+            CorporateActionKind::Split {
+                future_stock,
+                held_stock,
+            }
+            | CorporateActionKind::ReverseSplit {
+                future_stock,
+                held_stock,
+            } => held_stock / future_stock,
+            CorporateActionKind::SpinOff {
+                cost_allocation_percent,
+                ..
+            } => dec!(1) - cost_allocation_percent / dec!(100),
+        }
+    }
+}
+
+impl StockInformation {
+    // Product of the adjustment factors of every action that took effect after the given date.
+    pub fn get_cumulative_adjustment_factor(&self, date: DateTime<Utc>) -> Decimal {
+        self.get_corporate_actions()
+            .iter()
+            .filter(|action| action.date > date)
+            .fold(dec!(1), |acc, action| {
+                acc * action.get_price_adjustment_factor()
+            })
+    }
+
+    // Historical OHLC back-adjusted for every corporate action, the latest prices are left untouched.
+    pub fn get_adjusted_stock_data_series(&self) -> Vec<StockData> {
+        self.get_stock_data_series()
+            .iter()
+            .map(|stock_data| {
+                let factor = self.get_cumulative_adjustment_factor(stock_data.get_date());
+                StockData::new(
+                    stock_data.get_date(),
+                    stock_data.get_high() * factor,
+                    stock_data.get_low() * factor,
+                    stock_data.get_open() * factor,
+                    stock_data.get_close() * factor,
+                )
+            })
+            .collect()
+    }
+
+    // Last close on or before the given date, back-adjusted the same way as get_adjusted_stock_data_series.
+    pub fn get_adjusted_closing_price_as_of(&self, date: DateTime<Utc>) -> Option<Decimal> {
+        self.get_stock_data_series()
+            .iter()
+            .filter(|stock_data| stock_data.get_date() <= date)
+            .max_by_key(|stock_data| stock_data.get_date())
+            .map(|stock_data| {
+                stock_data.get_close()
+                    * self.get_cumulative_adjustment_factor(stock_data.get_date())
+            })
+    }
+
+    fn get_closing_price_before(&self, date: DateTime<Utc>) -> Option<Decimal> {
+        self.get_stock_data_series()
+            .iter()
+            .filter(|stock_data| stock_data.get_date() < date)
+            .max_by_key(|stock_data| stock_data.get_date())
+            .map(|stock_data| stock_data.get_close())
+    }
+}
+
+impl StockPortfolio {
+    // Records the corporate actions of a symbol into the ledger for the shares held on each effective date.
+    // Fractional shares left by a split are sold as cash-in-lieu at the post-split price of the last close
+    // before the action. Fractional spun-off shares are kept. Actions already in the ledger are skipped, so
+    // applying the same actions again changes nothing.
+    pub fn apply_corporate_actions(
+        &mut self,
+        stock_information: &StockInformation,
+    ) -> Result<Vec<Transaction>, Box<dyn Error>> {
+        let symbol = stock_information.get_symbol().to_string();
+        let mut transactions: Vec<Transaction> = vec![];
+
+        for action in stock_information.get_corporate_actions() {
+            let action_transaction = match &action.kind {
+                CorporateActionKind::Split {
+                    future_stock,
+                    held_stock,
+                }
+                | CorporateActionKind::ReverseSplit {
+                    future_stock,
+                    held_stock,
+                } => Transaction::new_split(
+                    action.date,
+                    symbol.clone(),
+                    *future_stock,
+                    *held_stock,
+                    vec![],
+                )?,
+                CorporateActionKind::SpinOff {
+                    new_symbol,
+                    new_shares_per_share,
+                    cost_allocation_percent,
+                } => Transaction::new(
+                    action.date,
+                    TransactionKind::SpinOff {
+                        symbol: symbol.clone(),
+                        new_symbol: new_symbol.clone(),
+                        new_shares_per_share: *new_shares_per_share,
+                        cost_allocation_percent: *cost_allocation_percent,
+                    },
+                    vec![],
+                ),
+            };
+
+            let is_applied =
+                self.ledger
+                    .get_transactions_by_symbol(&symbol)
+                    .iter()
+                    .any(|transaction| {
+                        transaction.date == action_transaction.date
+                            && transaction.kind == action_transaction.kind
+                    });
+            if is_applied {
+                continue;
+            }
+
+            let shares_held = self
+                .get_open_lots_as_of(action.date)
+                .iter()
+                .filter(|lot| lot.symbol == symbol)
+                .fold(dec!(0), |acc, lot| acc + lot.shares);
+
+            if shares_held == dec!(0) {
+                continue;
+            }
+
+            let mut action_transactions: Vec<Transaction> = vec![action_transaction];
+
+            if let CorporateActionKind::Split {
+                future_stock,
+                held_stock,
+            }
+            | CorporateActionKind::ReverseSplit {
+                future_stock,
+                held_stock,
+            } = &action.kind
+            {
+                let fractional_shares = ((future_stock * shares_held) / held_stock).fract();
+                if fractional_shares > dec!(0) {
+                    let share_price = match stock_information.get_closing_price_before(action.date)
+                    {
+                        Some(share_price) => share_price,
+                        None => Err("No closing price before corporate action")?,
+                    };
+                    let (_, post_split_share_price, _) = StockInformation::get_post_split_data(
+                        *future_stock,
+                        *held_stock,
+                        shares_held,
+                        share_price,
+                    );
+
+                    action_transactions.push(Transaction::new(
+                        action.date,
+                        TransactionKind::Sell {
+                            symbol: symbol.clone(),
+                            shares: fractional_shares,
+                            price: post_split_share_price,
+                        },
+                        vec![],
+                    ));
+                }
+            }
+
+            for transaction in action_transactions {
+                self.record(transaction.clone())?;
+                transactions.push(transaction);
+            }
+        }
+
+        Ok(transactions)
+    }
+}
@@ -0,0 +1,309 @@
+#[cfg(test)]
+use crate::stock_market::backtest::{Backtester, BarContext, Signal, Strategy};
+use crate::stock_market::corporate_action::*;
+use crate::stock_market::generate_utc_date_from_date_string;
+use crate::stock_market::risk::RiskMetrics;
+use crate::stock_market::stock_market::{StockData, StockInformation};
+use crate::stock_market::stock_portfolio::StockPortfolio;
+use crate::stock_market::transaction_ledger::{Transaction, TransactionKind, TransactionLedger};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn generate_stock_information(closing_prices: Vec<Decimal>) -> StockInformation {
+    let stock_data_series = closing_prices
+        .into_iter()
+        .enumerate()
+        .map(|(idx, close)| {
+            StockData::new(
+                generate_utc_date_from_date_string(&format!("10-{:02}-2022 00:00", idx + 1)),
+                close,
+                close,
+                close,
+                close,
+            )
+        })
+        .collect();
+    StockInformation::new(
+        "BenCorpo".to_string(),
+        "BNCRP".to_string(),
+        stock_data_series,
+    )
+}
+
+fn generate_portfolio(shares: Decimal, price: Decimal) -> StockPortfolio {
+    let mut portfolio = StockPortfolio::new(TransactionLedger::new());
+    portfolio
+        .record(Transaction::new(
+            generate_utc_date_from_date_string("10-01-2022 00:00"),
+            TransactionKind::Deposit { amount: dec!(1000) },
+            vec![],
+        ))
+        .unwrap();
+    portfolio
+        .record(Transaction::new(
+            generate_utc_date_from_date_string("10-01-2022 00:00"),
+            TransactionKind::Buy {
+                symbol: "BNCRP".to_string(),
+                shares,
+                price,
+            },
+            vec![],
+        ))
+        .unwrap();
+    portfolio
+}
+
+#[test]
+fn it_back_adjusts_stock_data_series() {
+    let mut stock_information =
+        generate_stock_information(vec![dec!(100), dec!(100), dec!(50), dec!(50), dec!(500)]);
+    stock_information.add_corporate_action(
+        CorporateAction::new(
+            generate_utc_date_from_date_string("10-05-2022 00:00"),
+            CorporateActionKind::ReverseSplit {
+                future_stock: dec!(1),
+                held_stock: dec!(10),
+            },
+        )
+        .unwrap(),
+    );
+    stock_information.add_corporate_action(
+        CorporateAction::new(
+            generate_utc_date_from_date_string("10-03-2022 00:00"),
+            CorporateActionKind::Split {
+                future_stock: dec!(2),
+                held_stock: dec!(1),
+            },
+        )
+        .unwrap(),
+    );
+
+    let adjusted_closing_prices = stock_information
+        .get_adjusted_stock_data_series()
+        .iter()
+        .map(|stock_data| stock_data.get_close())
+        .collect::<Vec<Decimal>>();
+
+    assert_eq!(
+        stock_information.get_corporate_actions()[0].date,
+        generate_utc_date_from_date_string("10-03-2022 00:00")
+    );
+    assert_eq!(adjusted_closing_prices, vec![dec!(500); 5]);
+    assert_eq!(
+        stock_information.get_stock_data_series()[0].get_close(),
+        dec!(100)
+    );
+}
+
+#[test]
+fn it_applies_split_with_cash_in_lieu() {
+    let mut stock_information = generate_stock_information(vec![dec!(60), dec!(90), dec!(60)]);
+    stock_information.add_corporate_action(
+        CorporateAction::new(
+            generate_utc_date_from_date_string("10-03-2022 00:00"),
+            CorporateActionKind::Split {
+                future_stock: dec!(3),
+                held_stock: dec!(2),
+            },
+        )
+        .unwrap(),
+    );
+    let mut portfolio = generate_portfolio(dec!(15), dec!(60));
+
+    let transactions = portfolio
+        .apply_corporate_actions(&stock_information)
+        .unwrap();
+    let open_lots = portfolio.get_open_lots("BNCRP");
+
+    assert_eq!(transactions.len(), 2);
+    assert_eq!(transactions[1].get_cash_flow(), dec!(30));
+    assert_eq!(open_lots[0].shares, dec!(22));
+    assert_eq!(open_lots[0].cost_per_share, dec!(40));
+    assert_eq!(portfolio.get_net_gains("BNCRP"), dec!(10));
+    assert_eq!(portfolio.get_cash_balance(), dec!(130));
+}
+
+#[test]
+fn it_applies_reverse_split_with_cash_in_lieu() {
+    let mut stock_information = generate_stock_information(vec![dec!(10), dec!(8), dec!(80)]);
+    stock_information.add_corporate_action(
+        CorporateAction::new(
+            generate_utc_date_from_date_string("10-03-2022 00:00"),
+            CorporateActionKind::ReverseSplit {
+                future_stock: dec!(1),
+                held_stock: dec!(10),
+            },
+        )
+        .unwrap(),
+    );
+    let mut portfolio = generate_portfolio(dec!(25), dec!(10));
+
+    portfolio
+        .apply_corporate_actions(&stock_information)
+        .unwrap();
+    let open_lots = portfolio.get_open_lots("BNCRP");
+
+    assert_eq!(open_lots[0].shares, dec!(2));
+    assert_eq!(open_lots[0].cost_per_share, dec!(100));
+    assert_eq!(portfolio.get_net_gains("BNCRP"), dec!(-10));
+    assert_eq!(portfolio.get_cash_balance(), dec!(790));
+}
+
+#[test]
+fn it_applies_spin_off() {
+    let mut stock_information = generate_stock_information(vec![dec!(50), dec!(50), dec!(40)]);
+    stock_information.add_corporate_action(
+        CorporateAction::new(
+            generate_utc_date_from_date_string("10-03-2022 00:00"),
+            CorporateActionKind::SpinOff {
+                new_symbol: "SPUN".to_string(),
+                new_shares_per_share: dec!(0.5),
+                cost_allocation_percent: dec!(20),
+            },
+        )
+        .unwrap(),
+    );
+    let mut portfolio = generate_portfolio(dec!(20), dec!(50));
+
+    portfolio
+        .apply_corporate_actions(&stock_information)
+        .unwrap();
+    let parent_lots = portfolio.get_open_lots("BNCRP");
+    let spun_off_lots = portfolio.get_open_lots("SPUN");
+
+    assert_eq!(
+        stock_information.get_adjusted_stock_data_series()[0].get_close(),
+        dec!(40)
+    );
+    assert_eq!(parent_lots[0].shares, dec!(20));
+    assert_eq!(parent_lots[0].cost_per_share, dec!(40));
+    assert_eq!(spun_off_lots[0].shares, dec!(10));
+    assert_eq!(spun_off_lots[0].cost_per_share, dec!(20));
+    assert_eq!(spun_off_lots[0].date, parent_lots[0].date);
+}
+
+#[test]
+fn it_skips_corporate_actions_without_holdings() {
+    let mut stock_information = generate_stock_information(vec![dec!(60), dec!(90), dec!(60)]);
+    stock_information.add_corporate_action(
+        CorporateAction::new(
+            generate_utc_date_from_date_string("09-01-2022 00:00"),
+            CorporateActionKind::Split {
+                future_stock: dec!(3),
+                held_stock: dec!(2),
+            },
+        )
+        .unwrap(),
+    );
+    let mut portfolio = generate_portfolio(dec!(15), dec!(60));
+
+    let transactions = portfolio
+        .apply_corporate_actions(&stock_information)
+        .unwrap();
+
+    assert!(transactions.is_empty());
+    assert_eq!(portfolio.get_shares_held("BNCRP"), dec!(15));
+}
+
+#[test]
+fn it_applies_corporate_actions_only_once() {
+    let mut stock_information = generate_stock_information(vec![dec!(60), dec!(90), dec!(60)]);
+    stock_information.add_corporate_action(
+        CorporateAction::new(
+            generate_utc_date_from_date_string("10-03-2022 00:00"),
+            CorporateActionKind::Split {
+                future_stock: dec!(3),
+                held_stock: dec!(2),
+            },
+        )
+        .unwrap(),
+    );
+    let mut portfolio = generate_portfolio(dec!(15), dec!(60));
+
+    portfolio
+        .apply_corporate_actions(&stock_information)
+        .unwrap();
+    let transactions = portfolio
+        .apply_corporate_actions(&stock_information)
+        .unwrap();
+
+    assert!(transactions.is_empty());
+    assert_eq!(portfolio.get_shares_held("BNCRP"), dec!(22));
+    assert_eq!(portfolio.get_cash_balance(), dec!(130));
+}
+
+#[test]
+fn it_rejects_invalid_corporate_action_ratios() {
+    let date = generate_utc_date_from_date_string("10-03-2022 00:00");
+    let split = |future_stock, held_stock| CorporateActionKind::Split {
+        future_stock,
+        held_stock,
+    };
+    let reverse_split = |future_stock, held_stock| CorporateActionKind::ReverseSplit {
+        future_stock,
+        held_stock,
+    };
+
+    assert!(CorporateAction::new(date, split(dec!(2), dec!(0))).is_err());
+    assert!(CorporateAction::new(date, split(dec!(1), dec!(10))).is_err());
+    assert!(CorporateAction::new(date, reverse_split(dec!(0), dec!(10))).is_err());
+    assert!(CorporateAction::new(date, reverse_split(dec!(2), dec!(1))).is_err());
+    assert!(CorporateAction::new(
+        date,
+        CorporateActionKind::SpinOff {
+            new_symbol: "SPUN".to_string(),
+            new_shares_per_share: dec!(0.5),
+            cost_allocation_percent: dec!(100),
+        },
+    )
+    .is_err());
+}
+
+struct BuyAtStartStrategy {}
+
+impl Strategy for BuyAtStartStrategy {
+    fn on_bar(&mut self, context: &BarContext) -> Signal {
+        match context.idx {
+            0 => Signal::Buy,
+            _ => Signal::Hold,
+        }
+    }
+}
+
+#[test]
+fn it_measures_returns_across_a_split_on_adjusted_prices() {
+    let mut stock_information =
+        generate_stock_information(vec![dec!(100), dec!(102), dec!(51), dec!(52)]);
+    stock_information.add_corporate_action(
+        CorporateAction::new(
+            generate_utc_date_from_date_string("10-03-2022 00:00"),
+            CorporateActionKind::Split {
+                future_stock: dec!(2),
+                held_stock: dec!(1),
+            },
+        )
+        .unwrap(),
+    );
+
+    let returns = RiskMetrics::get_stock_returns(&stock_information).unwrap();
+    let result = Backtester::new(dec!(1000), dec!(0), vec![])
+        .run(&stock_information, &mut BuyAtStartStrategy {})
+        .unwrap();
+    let equities = result
+        .equity_curve
+        .iter()
+        .map(|equity_point| equity_point.equity)
+        .collect::<Vec<Decimal>>();
+
+    assert_eq!(returns[0], dec!(2));
+    assert_eq!(returns[1], dec!(0));
+    assert!(returns[2] > dec!(0));
+    assert_eq!(
+        stock_information.get_moving_averages(2),
+        Some(vec![dec!(50.5), dec!(51), dec!(51.5)])
+    );
+    assert_eq!(
+        equities,
+        vec![dec!(1000), dec!(1000), dec!(1000), dec!(1019)]
+    );
+}
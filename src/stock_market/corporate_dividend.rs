@@ -0,0 +1,342 @@
+use std::error::Error;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use crate::stock_market::{
+    business_organization::Corporation,
+    cap_table::{CapTable, ShareClass},
+    corporate_action::{CorporateAction, CorporateActionKind},
+    dividend::DividendEvent,
+};
+
+// Dividend a preferred series is entitled to before common shareholders get anything. Unpaid dividends of a
+// cumulative series are carried as arrears and paid first on later declarations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PreferredDividendTerms {
+    pub series: String,
+    pub dividend_per_share: Decimal,
+    pub cumulative: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DividendKind {
+    // Total amount paid out.
+    Cash(Decimal),
+    // New common shares as a percent of the common shares held.
+    Stock(Decimal),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DividendDistribution {
+    pub holder: String,
+    pub share_class: ShareClass,
+    pub shares: u64,
+    pub cash: Decimal,
+    pub new_shares: u64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DividendDeclaration {
+    pub ex_date: DateTime<Utc>,
+    pub record_date: DateTime<Utc>,
+    pub pay_date: DateTime<Utc>,
+    pub kind: DividendKind,
+    pub preferred_paid: Decimal,
+    // Part of preferred_paid that settled arrears of earlier declarations.
+    pub arrears_paid: Decimal,
+    pub common_paid: Decimal,
+    pub common_dividend_per_share: Decimal,
+    pub distributions: Vec<DividendDistribution>,
+}
+
+impl DividendDeclaration {
+    pub fn get_distributions(&self, holder: &str) -> Vec<&DividendDistribution> {
+        self.distributions
+            .iter()
+            .filter(|distribution| distribution.holder == holder)
+            .collect()
+    }
+
+    // The declaration as seen by a common shareholder, to be added to the company's StockInformation.
+    pub fn get_dividend_event(&self) -> Option<DividendEvent> {
+        match self.kind {
+            DividendKind::Cash(_) if self.common_dividend_per_share > dec!(0) => {
+                Some(DividendEvent::new(
+                    self.ex_date,
+                    self.record_date,
+                    self.pay_date,
+                    self.common_dividend_per_share,
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    // A stock dividend trades like a split, e.g. 10% is 110 for 100.
+    pub fn get_corporate_action(&self) -> Option<CorporateAction> {
+        match self.kind {
+            DividendKind::Stock(percent) => CorporateAction::new(
+                self.ex_date,
+                CorporateActionKind::Split {
+                    future_stock: dec!(100) + percent,
+                    held_stock: dec!(100),
+                },
+            )
+            .ok(),
+            DividendKind::Cash(_) => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DividendLedger {
+    preferred_terms: Vec<PreferredDividendTerms>,
+    arrears: Vec<(String, Decimal)>,
+    declarations: Vec<DividendDeclaration>,
+}
+
+impl DividendLedger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_preferred_terms(&mut self, terms: PreferredDividendTerms) {
+        match self
+            .preferred_terms
+            .iter()
+            .position(|existing| existing.series == terms.series)
+        {
+            Some(idx) => self.preferred_terms[idx] = terms,
+            None => self.preferred_terms.push(terms),
+        }
+    }
+
+    pub fn get_preferred_terms(&self) -> &Vec<PreferredDividendTerms> {
+        &self.preferred_terms
+    }
+
+    pub fn get_arrears(&self, series: &str) -> Decimal {
+        self.arrears
+            .iter()
+            .find(|(name, _)| name == series)
+            .map(|(_, amount)| *amount)
+            .unwrap_or(dec!(0))
+    }
+
+    pub fn get_declarations(&self) -> &Vec<DividendDeclaration> {
+        &self.declarations
+    }
+
+    fn set_arrears(&mut self, series: &str, amount: Decimal) {
+        match self.arrears.iter().position(|(name, _)| name == series) {
+            Some(idx) => self.arrears[idx].1 = amount,
+            None => self.arrears.push((series.to_string(), amount)),
+        }
+    }
+}
+
+impl Corporation {
+    // Preferred series with terms are paid in the order they were set, arrears before the current dividend, and
+    // common shareholders split what is left by their shares. Entitlement follows the shares issued in the cap
+    // table, options are not entitled.
+    pub fn declare_cash_dividend(
+        &mut self,
+        ex_date: DateTime<Utc>,
+        record_date: DateTime<Utc>,
+        pay_date: DateTime<Utc>,
+        amount: Decimal,
+    ) -> Result<DividendDeclaration, Box<dyn Error>> {
+        if amount <= dec!(0) {
+            Err("Dividend must be positive")?;
+        }
+
+        let mut remaining = amount;
+        let mut preferred_paid = dec!(0);
+        let mut arrears_paid = dec!(0);
+        let mut distributions: Vec<DividendDistribution> = vec![];
+        let mut dividends = self.dividends.clone();
+
+        for terms in self.dividends.get_preferred_terms().iter() {
+            let share_class = ShareClass::Preferred(terms.series.clone());
+            let shares = self.cap_table.get_issued_shares_by_class(&share_class);
+            let current = Decimal::from(shares) * terms.dividend_per_share;
+            let arrears = dividends.get_arrears(&terms.series);
+
+            let series_arrears_paid = arrears.min(remaining);
+            remaining -= series_arrears_paid;
+            let current_paid = current.min(remaining);
+            remaining -= current_paid;
+
+            let unpaid = if terms.cumulative {
+                current - current_paid
+            } else {
+                dec!(0)
+            };
+            dividends.set_arrears(&terms.series, arrears - series_arrears_paid + unpaid);
+
+            arrears_paid += series_arrears_paid;
+            preferred_paid += series_arrears_paid + current_paid;
+            distributions.extend(get_cash_distributions(
+                &self.cap_table,
+                share_class,
+                series_arrears_paid + current_paid,
+            ));
+        }
+
+        let common_shares = self
+            .cap_table
+            .get_issued_shares_by_class(&ShareClass::Common);
+        if remaining > dec!(0) && common_shares == 0 {
+            Err("No common shares to distribute the dividend to")?;
+        }
+        let common_dividend_per_share = if common_shares == 0 {
+            dec!(0)
+        } else {
+            remaining / Decimal::from(common_shares)
+        };
+        distributions.extend(get_cash_distributions(
+            &self.cap_table,
+            ShareClass::Common,
+            remaining,
+        ));
+
+        let declaration = DividendDeclaration {
+            ex_date,
+            record_date,
+            pay_date,
+            kind: DividendKind::Cash(amount),
+            preferred_paid,
+            arrears_paid,
+            common_paid: remaining,
+            common_dividend_per_share,
+            distributions,
+        };
+        dividends.declarations.push(declaration.clone());
+        self.dividends = dividends;
+
+        Ok(declaration)
+    }
+
+    // New common shares are issued in the cap table, fractions of a share are dropped.
+    pub fn declare_stock_dividend(
+        &mut self,
+        ex_date: DateTime<Utc>,
+        record_date: DateTime<Utc>,
+        pay_date: DateTime<Utc>,
+        percent: Decimal,
+    ) -> Result<DividendDeclaration, Box<dyn Error>> {
+        if percent <= dec!(0) {
+            Err("Stock dividend must be positive")?;
+        }
+
+        let distributions = get_holder_shares(&self.cap_table, &ShareClass::Common)
+            .into_iter()
+            .map(|(holder, shares)| {
+                Ok(DividendDistribution {
+                    holder,
+                    share_class: ShareClass::Common,
+                    shares,
+                    cash: dec!(0),
+                    new_shares: u64::try_from(
+                        (Decimal::from(shares) * percent / dec!(100)).floor(),
+                    )?,
+                })
+            })
+            .collect::<Result<Vec<DividendDistribution>, Box<dyn Error>>>()?;
+        if distributions.is_empty() {
+            Err("No common shares to distribute the dividend to")?;
+        }
+
+        for distribution in distributions.iter() {
+            if distribution.new_shares > 0 {
+                self.cap_table.issue_shares(
+                    &distribution.holder,
+                    ShareClass::Common,
+                    distribution.new_shares,
+                    dec!(0),
+                );
+            }
+        }
+
+        let declaration = DividendDeclaration {
+            ex_date,
+            record_date,
+            pay_date,
+            kind: DividendKind::Stock(percent),
+            preferred_paid: dec!(0),
+            arrears_paid: dec!(0),
+            common_paid: dec!(0),
+            common_dividend_per_share: dec!(0),
+            distributions,
+        };
+        self.dividends.declarations.push(declaration.clone());
+
+        Ok(declaration)
+    }
+
+    // Cash dividends paid to a holder over every declaration, all share classes.
+    pub fn get_holder_dividends(&self, holder: &str) -> Decimal {
+        self.dividends
+            .get_declarations()
+            .iter()
+            .flat_map(|declaration| declaration.get_distributions(holder))
+            .fold(dec!(0), |acc, distribution| acc + distribution.cash)
+    }
+}
+
+fn get_holder_shares(cap_table: &CapTable, share_class: &ShareClass) -> Vec<(String, u64)> {
+    let mut holders: Vec<(String, u64)> = vec![];
+    for issuance in cap_table
+        .get_issuances()
+        .iter()
+        .filter(|issuance| &issuance.share_class == share_class)
+    {
+        match holders
+            .iter()
+            .position(|(holder, _)| holder == &issuance.holder)
+        {
+            Some(idx) => holders[idx].1 += issuance.shares,
+            None => holders.push((issuance.holder.clone(), issuance.shares)),
+        }
+    }
+    holders
+}
+
+// Splits amount by shares rounded to cents, the rounding difference goes to the largest holder (first on ties).
+fn get_cash_distributions(
+    cap_table: &CapTable,
+    share_class: ShareClass,
+    amount: Decimal,
+) -> Vec<DividendDistribution> {
+    let holders = get_holder_shares(cap_table, &share_class);
+    let total_shares = holders.iter().fold(0, |acc, (_, shares)| acc + shares);
+    if total_shares == 0 {
+        return vec![];
+    }
+
+    let mut distributions = holders
+        .iter()
+        .map(|(holder, shares)| DividendDistribution {
+            holder: holder.clone(),
+            share_class: share_class.clone(),
+            shares: *shares,
+            cash: (amount * Decimal::from(*shares) / Decimal::from(total_shares)).round_dp(2),
+            new_shares: 0,
+        })
+        .collect::<Vec<DividendDistribution>>();
+
+    let rounding_difference = amount
+        - distributions
+            .iter()
+            .fold(dec!(0), |acc, distribution| acc + distribution.cash);
+    if let Some(idx) = (0..distributions.len())
+        .rev()
+        .max_by_key(|idx| distributions[*idx].shares)
+    {
+        distributions[idx].cash += rounding_difference;
+    }
+
+    distributions
+}
@@ -0,0 +1,159 @@
+#[cfg(test)]
+use crate::stock_market::corporate_dividend::*;
+use crate::stock_market::generate_utc_date_from_date_string;
+use crate::stock_market::{
+    business_organization::{Corporation, Owner},
+    cap_table::ShareClass,
+    stock_market::StockInformation,
+};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+// Mark and Ben hold 60,000 and 40,000 common shares, Hello 10,000 Series A preferred entitled to 0.5 a share.
+fn generate_corporation(cumulative: bool) -> Corporation {
+    let owners = vec![
+        Owner::new("Mark".to_string(), dec!(60000)),
+        Owner::new("Ben".to_string(), dec!(40000)),
+    ];
+    let mut corporation = Corporation::new(owners, Some("KamoteCorp".to_string()), Some(100_000));
+    corporation.cap_table.issue_shares(
+        "Hello",
+        ShareClass::Preferred("Series A".to_string()),
+        10_000,
+        dec!(10),
+    );
+    corporation
+        .dividends
+        .set_preferred_terms(PreferredDividendTerms {
+            series: "Series A".to_string(),
+            dividend_per_share: dec!(0.5),
+            cumulative,
+        });
+    corporation
+}
+
+fn declare_cash_dividend(
+    corporation: &mut Corporation,
+    date_string: &str,
+    amount: Decimal,
+) -> DividendDeclaration {
+    let date = generate_utc_date_from_date_string(date_string);
+    corporation
+        .declare_cash_dividend(date, date, date, amount)
+        .unwrap()
+}
+
+#[test]
+fn it_pays_preferred_before_common_shareholders() {
+    let mut corporation = generate_corporation(true);
+    let declaration = declare_cash_dividend(&mut corporation, "03-01-2023 00:00", dec!(15000));
+
+    assert_eq!(declaration.preferred_paid, dec!(5000));
+    assert_eq!(declaration.common_paid, dec!(10000));
+    assert_eq!(declaration.common_dividend_per_share, dec!(0.1));
+    assert_eq!(declaration.get_distributions("Hello")[0].cash, dec!(5000));
+    assert_eq!(declaration.get_distributions("Mark")[0].cash, dec!(6000));
+    assert_eq!(declaration.get_distributions("Ben")[0].cash, dec!(4000));
+}
+
+#[test]
+fn it_carries_and_pays_cumulative_preferred_arrears() {
+    let mut corporation = generate_corporation(true);
+    let first = declare_cash_dividend(&mut corporation, "03-01-2022 00:00", dec!(3000));
+
+    assert_eq!(first.preferred_paid, dec!(3000));
+    assert_eq!(first.common_paid, dec!(0));
+    assert_eq!(first.get_dividend_event(), None);
+    assert_eq!(corporation.dividends.get_arrears("Series A"), dec!(2000));
+
+    let second = declare_cash_dividend(&mut corporation, "03-01-2023 00:00", dec!(10000));
+
+    assert_eq!(second.arrears_paid, dec!(2000));
+    assert_eq!(second.preferred_paid, dec!(7000));
+    assert_eq!(second.get_distributions("Mark")[0].cash, dec!(1800));
+    assert_eq!(corporation.dividends.get_arrears("Series A"), dec!(0));
+    assert_eq!(corporation.get_holder_dividends("Hello"), dec!(10000));
+}
+
+#[test]
+fn it_does_not_carry_arrears_for_non_cumulative_preferred() {
+    let mut corporation = generate_corporation(false);
+    declare_cash_dividend(&mut corporation, "03-01-2022 00:00", dec!(3000));
+    let second = declare_cash_dividend(&mut corporation, "03-01-2023 00:00", dec!(10000));
+
+    assert_eq!(second.preferred_paid, dec!(5000));
+    assert_eq!(second.common_paid, dec!(5000));
+    assert_eq!(corporation.dividends.get_arrears("Series A"), dec!(0));
+}
+
+#[test]
+fn it_gives_the_rounding_difference_to_the_largest_holder() {
+    let owners = vec![
+        Owner::new("Mark".to_string(), dec!(100)),
+        Owner::new("Ben".to_string(), dec!(100)),
+        Owner::new("Hello".to_string(), dec!(100)),
+    ];
+    let mut corporation = Corporation::new(owners, None, Some(300));
+    let declaration = declare_cash_dividend(&mut corporation, "03-01-2023 00:00", dec!(100));
+
+    let cash = declaration
+        .distributions
+        .iter()
+        .map(|distribution| distribution.cash)
+        .collect::<Vec<Decimal>>();
+    assert_eq!(cash, vec![dec!(33.34), dec!(33.33), dec!(33.33)]);
+}
+
+#[test]
+fn it_issues_common_shares_for_a_stock_dividend() {
+    let mut corporation = generate_corporation(true);
+    let date = generate_utc_date_from_date_string("03-01-2023 00:00");
+    let declaration = corporation
+        .declare_stock_dividend(date, date, date, dec!(10))
+        .unwrap();
+
+    assert_eq!(declaration.get_distributions("Mark")[0].new_shares, 6_000);
+    assert!(declaration.get_distributions("Hello").is_empty());
+    assert_eq!(
+        corporation
+            .cap_table
+            .get_issued_shares_by_class(&ShareClass::Common),
+        110_000
+    );
+    assert_eq!(
+        declaration
+            .get_corporate_action()
+            .unwrap()
+            .get_price_adjustment_factor()
+            .round_dp(4),
+        dec!(0.9091)
+    );
+}
+
+#[test]
+fn it_links_a_cash_dividend_to_shareholder_dividend_income() {
+    let mut corporation = generate_corporation(true);
+    let declaration = declare_cash_dividend(&mut corporation, "03-01-2023 00:00", dec!(15000));
+
+    let mut stock_information =
+        StockInformation::new("KamoteCorp".to_string(), "KMT".to_string(), vec![]);
+    stock_information.add_dividend_event(declaration.get_dividend_event().unwrap());
+
+    assert_eq!(
+        stock_information.get_trailing_twelve_month_dividends(generate_utc_date_from_date_string(
+            "12-31-2023 00:00"
+        )),
+        dec!(0.1)
+    );
+}
+
+#[test]
+fn it_returns_error_for_a_non_positive_dividend() {
+    let mut corporation = generate_corporation(true);
+    let date = generate_utc_date_from_date_string("03-01-2023 00:00");
+
+    assert!(corporation
+        .declare_cash_dividend(date, date, date, dec!(0))
+        .is_err());
+    assert!(corporation.dividends.get_declarations().is_empty());
+}
@@ -0,0 +1,308 @@
+use std::{error::Error, fs};
+
+use chrono::{DateTime, Utc};
+use plotters::{
+    prelude::{BitMapBackend, ChartBuilder, IntoDrawingArea, Rectangle, Text},
+    style::{Color, IntoFont, RGBColor, BLACK, WHITE},
+};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+use rust_decimal_macros::dec;
+
+use crate::stock_market::stock_market::StockInformation;
+
+// Square matrix where values[i][j] pairs symbols[i] with symbols[j].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SymbolMatrix {
+    pub symbols: Vec<String>,
+    pub values: Vec<Vec<Decimal>>,
+}
+
+impl SymbolMatrix {
+    pub fn get(&self, symbol_a: &str, symbol_b: &str) -> Option<Decimal> {
+        let idx_a = self.symbols.iter().position(|symbol| symbol == symbol_a)?;
+        let idx_b = self.symbols.iter().position(|symbol| symbol == symbol_b)?;
+        Some(self.values[idx_a][idx_b])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RollingSymbolMatrix {
+    pub date: DateTime<Utc>,
+    pub matrix: SymbolMatrix,
+}
+
+// returns[i] holds the percent returns of the i-th symbol, returns[i][k] being the return up to dates[k].
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlignedReturns {
+    pub dates: Vec<DateTime<Utc>>,
+    pub returns: Vec<Vec<Decimal>>,
+}
+
+#[derive(Debug)]
+pub struct Correlation {}
+
+impl Correlation {
+    // Percent returns of every symbol, computed only on dates traded by all of the symbols.
+    pub fn get_aligned_returns(stock_informations: &[StockInformation]) -> Option<AlignedReturns> {
+        let first_stock_information = stock_informations.first()?;
+
+        let common_dates = first_stock_information
+            .get_stock_data_series()
+            .iter()
+            .map(|stock_data| stock_data.get_date())
+            .filter(|date| {
+                stock_informations.iter().all(|stock_information| {
+                    stock_information
+                        .get_stock_data_series()
+                        .iter()
+                        .any(|stock_data| &stock_data.get_date() == date)
+                })
+            })
+            .collect::<Vec<DateTime<Utc>>>();
+
+        if common_dates.len() < 3 {
+            return None;
+        }
+
+        let returns = stock_informations
+            .iter()
+            .map(|stock_information| {
+                let closing_prices = common_dates
+                    .iter()
+                    .map(|date| stock_information.get_adjusted_closing_price_as_of(*date))
+                    .collect::<Option<Vec<Decimal>>>()?;
+
+                if closing_prices.contains(&dec!(0)) {
+                    return None;
+                }
+
+                Some(
+                    closing_prices
+                        .windows(2)
+                        .map(|window| ((window[1] - window[0]) / window[0]) * dec!(100))
+                        .collect::<Vec<Decimal>>(),
+                )
+            })
+            .collect::<Option<Vec<Vec<Decimal>>>>()?;
+
+        Some(AlignedReturns {
+            dates: common_dates[1..].to_vec(),
+            returns,
+        })
+    }
+
+    pub fn get_covariance_matrix(stock_informations: &[StockInformation]) -> Option<SymbolMatrix> {
+        let aligned_returns = Self::get_aligned_returns(stock_informations)?;
+        Some(SymbolMatrix {
+            symbols: get_symbols(stock_informations),
+            values: get_covariances(&aligned_returns.returns),
+        })
+    }
+
+    pub fn get_correlation_matrix(stock_informations: &[StockInformation]) -> Option<SymbolMatrix> {
+        let aligned_returns = Self::get_aligned_returns(stock_informations)?;
+        Some(SymbolMatrix {
+            symbols: get_symbols(stock_informations),
+            values: get_correlations(&get_covariances(&aligned_returns.returns)),
+        })
+    }
+
+    // One covariance matrix per date, each built from the returns of the trailing window ending on that date.
+    pub fn get_rolling_covariance_matrices(
+        stock_informations: &[StockInformation],
+        window: usize,
+    ) -> Option<Vec<RollingSymbolMatrix>> {
+        Self::get_rolling_matrices(stock_informations, window, get_covariances)
+    }
+
+    pub fn get_rolling_correlation_matrices(
+        stock_informations: &[StockInformation],
+        window: usize,
+    ) -> Option<Vec<RollingSymbolMatrix>> {
+        Self::get_rolling_matrices(stock_informations, window, |returns| {
+            get_correlations(&get_covariances(returns))
+        })
+    }
+
+    pub fn show_heatmap(
+        stock_informations: &[StockInformation],
+        directory: Option<String>,
+        height: Option<u32>,
+        width: Option<u32>,
+    ) -> Result<bool, Box<dyn Error>> {
+        let correlation_matrix = match Self::get_correlation_matrix(stock_informations) {
+            Some(correlation_matrix) => correlation_matrix,
+            None => Err("Insufficient stock data series length")?,
+        };
+        let symbols_len = correlation_matrix.symbols.len() as i32;
+
+        let dt = Utc::now();
+        let timestamp: i64 = dt.timestamp();
+
+        let dir = directory.unwrap_or("chart_outputs".to_string());
+
+        fs::create_dir_all(&dir)?;
+
+        let filepath = format!("{}/{}_correlation_heatmap.png", &dir, timestamp);
+        let drawing_area =
+            BitMapBackend::new(&filepath, (height.unwrap_or(1024), width.unwrap_or(768)))
+                .into_drawing_area();
+
+        drawing_area.fill(&WHITE)?;
+
+        let font_style = ("sans-serif", 25.0).into_font();
+        let symbols = correlation_matrix.symbols.clone();
+        let symbol_label = |idx: &i32| symbols.get(*idx as usize).cloned().unwrap_or_default();
+
+        let mut chart_builder = ChartBuilder::on(&drawing_area);
+        let mut chart = chart_builder
+            .margin(25)
+            .x_label_area_size(40)
+            .y_label_area_size(70)
+            .caption("Return Correlation", font_style)
+            .build_cartesian_2d(0..symbols_len, 0..symbols_len)?;
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .disable_y_mesh()
+            .x_labels(symbols_len as usize)
+            .y_labels(symbols_len as usize)
+            .x_label_formatter(&symbol_label)
+            .y_label_formatter(&symbol_label)
+            .draw()?;
+
+        let cells = correlation_matrix
+            .values
+            .iter()
+            .enumerate()
+            .flat_map(|(row, values)| {
+                values.iter().enumerate().map(move |(column, value)| {
+                    (row as i32, column as i32, value.to_f64().unwrap())
+                })
+            })
+            .collect::<Vec<(i32, i32, f64)>>();
+
+        chart.draw_series(cells.iter().map(|(row, column, value)| {
+            Rectangle::new(
+                [(*column, *row), (*column + 1, *row + 1)],
+                get_heatmap_color(*value).filled(),
+            )
+        }))?;
+
+        chart.draw_series(cells.iter().map(|(row, column, value)| {
+            Text::new(
+                format!("{:.2}", value),
+                (*column, *row + 1),
+                ("sans-serif", 20.0).into_font().color(&BLACK),
+            )
+        }))?;
+
+        drawing_area
+            .present()
+            .unwrap_or_else(|_| panic!("Cannot write into {:?}. Directory does not exists.", &dir));
+
+        println!("Correlation heatmap has been saved to {}", filepath);
+
+        Ok(true)
+    }
+
+    fn get_rolling_matrices(
+        stock_informations: &[StockInformation],
+        window: usize,
+        get_values: impl Fn(&[Vec<Decimal>]) -> Vec<Vec<Decimal>>,
+    ) -> Option<Vec<RollingSymbolMatrix>> {
+        let AlignedReturns { dates, returns } = Self::get_aligned_returns(stock_informations)?;
+
+        if window < 2 || window > dates.len() {
+            return None;
+        }
+
+        let symbols = get_symbols(stock_informations);
+
+        Some(
+            (window..=dates.len())
+                .map(|end| {
+                    let windowed_returns = returns
+                        .iter()
+                        .map(|symbol_returns| symbol_returns[end - window..end].to_vec())
+                        .collect::<Vec<Vec<Decimal>>>();
+
+                    RollingSymbolMatrix {
+                        date: dates[end - 1],
+                        matrix: SymbolMatrix {
+                            symbols: symbols.clone(),
+                            values: get_values(&windowed_returns),
+                        },
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
+fn get_symbols(stock_informations: &[StockInformation]) -> Vec<String> {
+    stock_informations
+        .iter()
+        .map(|stock_information| stock_information.get_symbol().to_string())
+        .collect()
+}
+
+// Sample covariance (n - 1) of every pair of return series.
+fn get_covariances(returns: &[Vec<Decimal>]) -> Vec<Vec<Decimal>> {
+    let means = returns
+        .iter()
+        .map(|series| {
+            series.iter().fold(dec!(0), |acc, value| acc + value) / Decimal::from(series.len())
+        })
+        .collect::<Vec<Decimal>>();
+
+    (0..returns.len())
+        .map(|i| {
+            (0..returns.len())
+                .map(|j| {
+                    let sum = returns[i]
+                        .iter()
+                        .zip(returns[j].iter())
+                        .fold(dec!(0), |acc, (a, b)| acc + (a - means[i]) * (b - means[j]));
+                    sum / Decimal::from(returns[i].len() - 1)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// Series without any movement have no defined correlation, they are reported as 0.
+fn get_correlations(covariances: &[Vec<Decimal>]) -> Vec<Vec<Decimal>> {
+    let standard_deviations = (0..covariances.len())
+        .map(|i| Decimal::from_f64(covariances[i][i].to_f64().unwrap().sqrt()).unwrap_or_default())
+        .collect::<Vec<Decimal>>();
+
+    (0..covariances.len())
+        .map(|i| {
+            (0..covariances.len())
+                .map(|j| {
+                    let divisor = standard_deviations[i] * standard_deviations[j];
+                    if divisor == dec!(0) {
+                        dec!(0)
+                    } else {
+                        (covariances[i][j] / divisor).max(dec!(-1)).min(dec!(1))
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// Red for -1, white for 0, blue for 1.
+fn get_heatmap_color(value: f64) -> RGBColor {
+    let intensity = (255.0 * (1.0 - value.abs().min(1.0))) as u8;
+    if value >= 0.0 {
+        RGBColor(intensity, intensity, 255)
+    } else {
+        RGBColor(255, intensity, intensity)
+    }
+}
@@ -0,0 +1,132 @@
+#[cfg(test)]
+use crate::stock_market::correlation::*;
+use crate::stock_market::generate_utc_date_from_date_string;
+use crate::stock_market::stock_market::{StockData, StockInformation};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn generate_stock_information(symbol: &str, closing_prices: Vec<Decimal>) -> StockInformation {
+    let stock_data_series = closing_prices
+        .into_iter()
+        .enumerate()
+        .map(|(idx, close)| {
+            StockData::new(
+                generate_utc_date_from_date_string(&format!("10-{:02}-2022 00:00", idx + 1)),
+                close,
+                close,
+                close,
+                close,
+            )
+        })
+        .collect();
+    StockInformation::new(symbol.to_string(), symbol.to_string(), stock_data_series)
+}
+
+fn generate_stock_informations() -> Vec<StockInformation> {
+    vec![
+        generate_stock_information(
+            "BNCRP",
+            vec![dec!(100), dec!(110), dec!(99), dec!(108.9), dec!(98.01)],
+        ),
+        generate_stock_information(
+            "MRKCRP",
+            vec![dec!(50), dec!(55), dec!(49.5), dec!(54.45), dec!(49.005)],
+        ),
+        generate_stock_information(
+            "KMTCRP",
+            vec![dec!(100), dec!(90), dec!(99), dec!(89.1), dec!(98.01)],
+        ),
+    ]
+}
+
+#[test]
+fn it_gets_covariance_matrix() {
+    let stock_informations = generate_stock_informations();
+
+    let covariance_matrix = Correlation::get_covariance_matrix(&stock_informations).unwrap();
+
+    assert_eq!(covariance_matrix.symbols.len(), 3);
+    assert_eq!(
+        covariance_matrix.get("BNCRP", "BNCRP").unwrap().round_dp(2),
+        dec!(133.33)
+    );
+    assert_eq!(
+        covariance_matrix
+            .get("BNCRP", "KMTCRP")
+            .unwrap()
+            .round_dp(2),
+        dec!(-133.33)
+    );
+    assert_eq!(covariance_matrix.get("BNCRP", "WALA"), None);
+}
+
+#[test]
+fn it_gets_correlation_matrix() {
+    let stock_informations = generate_stock_informations();
+
+    let correlation_matrix = Correlation::get_correlation_matrix(&stock_informations).unwrap();
+
+    assert_eq!(
+        correlation_matrix
+            .get("BNCRP", "MRKCRP")
+            .unwrap()
+            .round_dp(4),
+        dec!(1)
+    );
+    assert_eq!(
+        correlation_matrix
+            .get("MRKCRP", "KMTCRP")
+            .unwrap()
+            .round_dp(4),
+        dec!(-1)
+    );
+}
+
+#[test]
+fn it_gets_rolling_correlation_matrices() {
+    let stock_informations = generate_stock_informations();
+
+    let rolling_matrices =
+        Correlation::get_rolling_correlation_matrices(&stock_informations, 3).unwrap();
+
+    assert_eq!(rolling_matrices.len(), 2);
+    assert_eq!(
+        rolling_matrices[1].date,
+        generate_utc_date_from_date_string("10-05-2022 00:00")
+    );
+    assert_eq!(
+        rolling_matrices[1]
+            .matrix
+            .get("BNCRP", "KMTCRP")
+            .unwrap()
+            .round_dp(4),
+        dec!(-1)
+    );
+    assert_eq!(
+        Correlation::get_rolling_covariance_matrices(&stock_informations, 5),
+        None
+    );
+}
+
+#[test]
+fn it_only_uses_dates_common_to_all_symbols() {
+    let mut stock_informations = generate_stock_informations();
+    stock_informations.push(generate_stock_information(
+        "MAIKLI",
+        vec![dec!(10), dec!(11)],
+    ));
+
+    assert_eq!(
+        Correlation::get_correlation_matrix(&stock_informations),
+        None
+    );
+}
+
+#[test]
+fn it_shows_correlation_heatmap() {
+    let stock_informations = generate_stock_informations();
+
+    let chart = Correlation::show_heatmap(&stock_informations, None, None, None);
+
+    assert!(chart.is_ok());
+}
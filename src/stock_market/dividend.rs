@@ -0,0 +1,221 @@
+use std::error::Error;
+
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use crate::stock_market::{
+    stock_market::StockInformation,
+    stock_portfolio::StockPortfolio,
+    transaction_ledger::{Transaction, TransactionKind},
+};
+
+// Shares held before the ex-dividend date are entitled to amount per share, paid on pay_date.
+// Shares bought from the ex-dividend date on settle after record_date, so they are not on record.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DividendEvent {
+    pub ex_date: DateTime<Utc>,
+    pub record_date: DateTime<Utc>,
+    pub pay_date: DateTime<Utc>,
+    pub amount: Decimal,
+}
+
+impl DividendEvent {
+    pub fn new(
+        ex_date: DateTime<Utc>,
+        record_date: DateTime<Utc>,
+        pay_date: DateTime<Utc>,
+        amount: Decimal,
+    ) -> Self {
+        Self {
+            ex_date,
+            record_date,
+            pay_date,
+            amount,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DripReinvestment {
+    pub pay_date: DateTime<Utc>,
+    pub entitled_shares: Decimal,
+    pub dividend: Decimal,
+    pub price: Decimal,
+    pub shares_bought: Decimal,
+    pub shares_after: Decimal,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DripResult {
+    pub final_shares: Decimal,
+    pub total_dividends: Decimal,
+    // Dividends not reinvested because whole shares could not be bought or no price was available.
+    pub residual_cash: Decimal,
+    pub reinvestments: Vec<DripReinvestment>,
+}
+
+impl StockInformation {
+    // Dividends per share that went ex-dividend within the year ending on the given date.
+    pub fn get_trailing_twelve_month_dividends(&self, as_of: DateTime<Utc>) -> Decimal {
+        let year_ago = as_of - Duration::days(365);
+
+        self.get_dividend_events()
+            .iter()
+            .filter(|event| event.ex_date > year_ago && event.ex_date <= as_of)
+            .fold(dec!(0), |acc, event| acc + event.amount)
+    }
+
+    pub fn get_trailing_yield(&self, as_of: DateTime<Utc>) -> Option<Decimal> {
+        let price = self.get_closing_price_as_of(as_of)?;
+        if price == dec!(0) {
+            return None;
+        }
+
+        Some(StockInformation::get_yield_rate(
+            self.get_trailing_twelve_month_dividends(as_of),
+            price,
+        ))
+    }
+
+    // Latest dividend annualized over the number of payments made in a year (e.g. 4 for quarterly).
+    pub fn get_forward_yield(
+        &self,
+        as_of: DateTime<Utc>,
+        payments_per_year: u32,
+    ) -> Option<Decimal> {
+        let price = self.get_closing_price_as_of(as_of)?;
+        let latest_event = self
+            .get_dividend_events()
+            .iter()
+            .rev()
+            .find(|event| event.ex_date <= as_of)?;
+
+        if price == dec!(0) {
+            return None;
+        }
+
+        Some(StockInformation::get_yield_rate(
+            latest_event.amount * Decimal::from(payments_per_year),
+            price,
+        ))
+    }
+
+    // Reinvests every dividend going ex-dividend between from and to at the closing price of its pay date.
+    // With fractional set to false only whole shares are bought and the remainder is carried to the next dividend.
+    pub fn simulate_drip(
+        &self,
+        shares: Decimal,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        fractional: bool,
+    ) -> DripResult {
+        let mut reinvestments: Vec<DripReinvestment> = vec![];
+        let mut total_dividends = dec!(0);
+        let mut residual_cash = dec!(0);
+
+        for event in self
+            .get_dividend_events()
+            .iter()
+            .filter(|event| event.ex_date >= from && event.ex_date <= to && event.pay_date <= to)
+        {
+            // Shares bought on a pay date before this ex-dividend date are entitled as well.
+            let entitled_shares = shares
+                + reinvestments
+                    .iter()
+                    .filter(|reinvestment| reinvestment.pay_date < event.ex_date)
+                    .fold(dec!(0), |acc, reinvestment| {
+                        acc + reinvestment.shares_bought
+                    });
+            let dividend = entitled_shares * event.amount;
+            total_dividends += dividend;
+            residual_cash += dividend;
+
+            let price = match self.get_closing_price_as_of(event.pay_date) {
+                Some(price) if price > dec!(0) => price,
+                _ => continue,
+            };
+
+            let shares_bought = if fractional {
+                residual_cash / price
+            } else {
+                (residual_cash / price).floor()
+            };
+            residual_cash -= shares_bought * price;
+
+            let shares_after = shares
+                + reinvestments.iter().fold(dec!(0), |acc, reinvestment| {
+                    acc + reinvestment.shares_bought
+                })
+                + shares_bought;
+
+            reinvestments.push(DripReinvestment {
+                pay_date: event.pay_date,
+                entitled_shares,
+                dividend,
+                price,
+                shares_bought,
+                shares_after,
+            });
+        }
+
+        DripResult {
+            final_shares: reinvestments
+                .last()
+                .map(|reinvestment| reinvestment.shares_after)
+                .unwrap_or(shares),
+            total_dividends,
+            residual_cash,
+            reinvestments,
+        }
+    }
+}
+
+impl StockPortfolio {
+    // Records a dividend on the pay date of every event for the shares held just before its ex-dividend date.
+    // Shares bought on or after the ex-dividend date are not entitled, shares sold from then on still are.
+    // Dividends already in the ledger are skipped, so applying the same events again changes nothing.
+    pub fn apply_dividends(
+        &mut self,
+        stock_information: &StockInformation,
+    ) -> Result<Vec<Transaction>, Box<dyn Error>> {
+        let symbol = stock_information.get_symbol().to_string();
+        let mut transactions: Vec<Transaction> = vec![];
+
+        for event in stock_information.get_dividend_events() {
+            let entitled_shares = self
+                .get_open_lots_as_of(event.ex_date - Duration::nanoseconds(1))
+                .iter()
+                .filter(|lot| lot.symbol == symbol)
+                .fold(dec!(0), |acc, lot| acc + lot.shares);
+            if entitled_shares == dec!(0) {
+                continue;
+            }
+
+            let transaction = Transaction::new(
+                event.pay_date,
+                TransactionKind::Dividend {
+                    symbol: symbol.clone(),
+                    amount: entitled_shares * event.amount,
+                },
+                vec![],
+            );
+
+            let is_applied =
+                self.ledger
+                    .get_transactions_by_symbol(&symbol)
+                    .iter()
+                    .any(|recorded| {
+                        recorded.date == transaction.date && recorded.kind == transaction.kind
+                    });
+            if is_applied {
+                continue;
+            }
+
+            self.record(transaction.clone())?;
+            transactions.push(transaction);
+        }
+
+        Ok(transactions)
+    }
+}
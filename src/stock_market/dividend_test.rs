@@ -0,0 +1,238 @@
+#[cfg(test)]
+use crate::stock_market::dividend::*;
+use crate::stock_market::generate_utc_date_from_date_string;
+use crate::stock_market::stock_market::{StockData, StockInformation};
+use crate::stock_market::stock_portfolio::StockPortfolio;
+use crate::stock_market::transaction_ledger::{Transaction, TransactionKind, TransactionLedger};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn generate_stock_information(closing_prices: Vec<(&str, Decimal)>) -> StockInformation {
+    let stock_data_series = closing_prices
+        .into_iter()
+        .map(|(date_string, close)| {
+            StockData::new(
+                generate_utc_date_from_date_string(date_string),
+                close,
+                close,
+                close,
+                close,
+            )
+        })
+        .collect();
+    let mut stock_information = StockInformation::new(
+        "BenCorpo".to_string(),
+        "BNCRP".to_string(),
+        stock_data_series,
+    );
+
+    // Quarterly dividends of 1 per share, added out of order.
+    for (ex_date, record_date, pay_date) in [
+        ("09-01-2022 00:00", "09-02-2022 00:00", "09-15-2022 00:00"),
+        ("03-01-2022 00:00", "03-02-2022 00:00", "03-15-2022 00:00"),
+        ("12-01-2022 00:00", "12-02-2022 00:00", "12-15-2022 00:00"),
+        ("06-01-2022 00:00", "06-02-2022 00:00", "06-15-2022 00:00"),
+    ] {
+        stock_information.add_dividend_event(DividendEvent::new(
+            generate_utc_date_from_date_string(ex_date),
+            generate_utc_date_from_date_string(record_date),
+            generate_utc_date_from_date_string(pay_date),
+            dec!(1),
+        ));
+    }
+    stock_information
+}
+
+fn generate_default_stock_information() -> StockInformation {
+    generate_stock_information(vec![
+        ("01-01-2022 00:00", dec!(10)),
+        ("04-15-2022 00:00", dec!(20)),
+        ("07-15-2022 00:00", dec!(25)),
+        ("10-15-2022 00:00", dec!(50)),
+    ])
+}
+
+#[test]
+fn it_keeps_dividend_events_ordered_by_ex_date() {
+    let stock_information = generate_default_stock_information();
+
+    let ex_dates = stock_information
+        .get_dividend_events()
+        .iter()
+        .map(|event| event.ex_date)
+        .collect::<Vec<DateTime<Utc>>>();
+
+    assert_eq!(
+        ex_dates,
+        vec![
+            generate_utc_date_from_date_string("03-01-2022 00:00"),
+            generate_utc_date_from_date_string("06-01-2022 00:00"),
+            generate_utc_date_from_date_string("09-01-2022 00:00"),
+            generate_utc_date_from_date_string("12-01-2022 00:00"),
+        ]
+    );
+}
+
+#[test]
+fn it_gets_trailing_twelve_month_dividends() {
+    let stock_information = generate_default_stock_information();
+
+    assert_eq!(
+        stock_information.get_trailing_twelve_month_dividends(generate_utc_date_from_date_string(
+            "06-30-2022 00:00"
+        )),
+        dec!(2)
+    );
+    assert_eq!(
+        stock_information.get_trailing_twelve_month_dividends(generate_utc_date_from_date_string(
+            "12-31-2022 00:00"
+        )),
+        dec!(4)
+    );
+    assert_eq!(
+        stock_information.get_trailing_twelve_month_dividends(generate_utc_date_from_date_string(
+            "06-15-2023 00:00"
+        )),
+        dec!(2)
+    );
+}
+
+#[test]
+fn it_gets_trailing_and_forward_yield() {
+    let stock_information = generate_default_stock_information();
+    let as_of = generate_utc_date_from_date_string("06-30-2022 00:00");
+
+    assert_eq!(
+        stock_information.get_trailing_yield(as_of),
+        Some(dec!(10.0))
+    );
+    assert_eq!(
+        stock_information.get_forward_yield(as_of, 4),
+        Some(dec!(20.0))
+    );
+    assert_eq!(
+        stock_information
+            .get_forward_yield(generate_utc_date_from_date_string("02-01-2022 00:00"), 4),
+        None
+    );
+}
+
+#[test]
+fn it_simulates_drip_with_fractional_shares() {
+    let stock_information = generate_default_stock_information();
+
+    let drip_result = stock_information.simulate_drip(
+        dec!(100),
+        generate_utc_date_from_date_string("01-01-2022 00:00"),
+        generate_utc_date_from_date_string("12-31-2022 00:00"),
+        true,
+    );
+
+    let shares_bought = drip_result
+        .reinvestments
+        .iter()
+        .map(|reinvestment| reinvestment.shares_bought)
+        .collect::<Vec<Decimal>>();
+
+    assert_eq!(
+        shares_bought,
+        vec![dec!(10), dec!(5.5), dec!(4.62), dec!(2.4024)]
+    );
+    assert_eq!(drip_result.final_shares, dec!(122.5224));
+    assert_eq!(drip_result.total_dividends, dec!(445.62));
+    assert_eq!(drip_result.residual_cash, dec!(0));
+}
+
+#[test]
+fn it_simulates_drip_with_whole_shares() {
+    let stock_information = generate_default_stock_information();
+
+    let drip_result = stock_information.simulate_drip(
+        dec!(100),
+        generate_utc_date_from_date_string("01-01-2022 00:00"),
+        generate_utc_date_from_date_string("12-31-2022 00:00"),
+        false,
+    );
+
+    let shares_bought = drip_result
+        .reinvestments
+        .iter()
+        .map(|reinvestment| reinvestment.shares_bought)
+        .collect::<Vec<Decimal>>();
+
+    assert_eq!(shares_bought, vec![dec!(10), dec!(5), dec!(5), dec!(2)]);
+    assert_eq!(drip_result.final_shares, dec!(122));
+    assert_eq!(drip_result.total_dividends, dec!(445));
+    assert_eq!(drip_result.residual_cash, dec!(20));
+}
+
+#[test]
+fn it_pays_dividends_to_shares_held_before_ex_date() {
+    let stock_information = generate_default_stock_information();
+    let mut portfolio = StockPortfolio::new(TransactionLedger::new());
+    for (date_string, kind) in [
+        (
+            "01-01-2022 00:00",
+            TransactionKind::Deposit {
+                amount: dec!(10000),
+            },
+        ),
+        (
+            "01-01-2022 00:00",
+            TransactionKind::Buy {
+                symbol: "BNCRP".to_string(),
+                shares: dec!(100),
+                price: dec!(10),
+            },
+        ),
+        // Bought on the ex-dividend date, entitled from the next dividend on.
+        (
+            "03-01-2022 00:00",
+            TransactionKind::Buy {
+                symbol: "BNCRP".to_string(),
+                shares: dec!(50),
+                price: dec!(10),
+            },
+        ),
+        // Sold after the ex-dividend date, still entitled to that dividend.
+        (
+            "06-02-2022 00:00",
+            TransactionKind::Sell {
+                symbol: "BNCRP".to_string(),
+                shares: dec!(150),
+                price: dec!(20),
+            },
+        ),
+    ] {
+        portfolio
+            .record(Transaction::new(
+                generate_utc_date_from_date_string(date_string),
+                kind,
+                vec![],
+            ))
+            .unwrap();
+    }
+
+    let dividends = portfolio.apply_dividends(&stock_information).unwrap();
+    let reapplied_dividends = portfolio.apply_dividends(&stock_information).unwrap();
+
+    assert_eq!(
+        dividends
+            .iter()
+            .map(|transaction| (transaction.date, transaction.get_cash_flow()))
+            .collect::<Vec<(DateTime<Utc>, Decimal)>>(),
+        vec![
+            (
+                generate_utc_date_from_date_string("03-15-2022 00:00"),
+                dec!(100)
+            ),
+            (
+                generate_utc_date_from_date_string("06-15-2022 00:00"),
+                dec!(150)
+            ),
+        ]
+    );
+    assert!(reapplied_dividends.is_empty());
+    assert_eq!(portfolio.get_cash_balance(), dec!(11750));
+}
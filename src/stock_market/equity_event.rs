@@ -0,0 +1,132 @@
+use std::error::Error;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use crate::stock_market::business_organization::{split_by_ratios, Owner};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum EquityEventKind {
+    Contribution {
+        owner: String,
+        amount: Decimal,
+    },
+    // Withdrawing the whole investment takes the owner out of the business.
+    Withdrawal {
+        owner: String,
+        amount: Decimal,
+    },
+    Admission {
+        owner: String,
+        investment: Decimal,
+    },
+    // The seller's whole stake goes to the buyer, the price is paid between them and leaves the capital unchanged.
+    // Without a buyer the business pays the price to buy the stake back. A price above the seller's capital is a
+    // bonus paid out of the remaining owners' capital, a price below it a bonus to them, in proportion to capital.
+    Buyout {
+        seller: String,
+        buyer: Option<String>,
+        price: Decimal,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EquityEvent {
+    pub date: DateTime<Utc>,
+    pub kind: EquityEventKind,
+}
+
+impl EquityEvent {
+    pub fn new(date: DateTime<Utc>, kind: EquityEventKind) -> Self {
+        Self { date, kind }
+    }
+
+    pub fn apply(&self, owners: &mut Vec<Owner>) -> Result<(), Box<dyn Error>> {
+        match &self.kind {
+            EquityEventKind::Contribution { owner, amount } => {
+                if *amount <= dec!(0) {
+                    Err("Contribution must be positive")?;
+                }
+                let idx = get_owner_idx(owners, owner)?;
+                owners[idx] = Owner::new(owner.clone(), owners[idx].get_investment() + amount);
+            }
+            EquityEventKind::Withdrawal { owner, amount } => {
+                if *amount <= dec!(0) {
+                    Err("Withdrawal must be positive")?;
+                }
+                let idx = get_owner_idx(owners, owner)?;
+                let investment = owners[idx].get_investment() - amount;
+                if investment < dec!(0) {
+                    Err("Withdrawal is larger than the owner's investment")?;
+                }
+
+                if investment == dec!(0) {
+                    owners.remove(idx);
+                } else {
+                    owners[idx] = Owner::new(owner.clone(), investment);
+                }
+            }
+            EquityEventKind::Admission { owner, investment } => {
+                if *investment <= dec!(0) {
+                    Err("Investment of a new owner must be positive")?;
+                }
+                if owners.iter().any(|existing| existing.get_name() == owner) {
+                    Err("Owner already exists")?;
+                }
+                owners.push(Owner::new(owner.clone(), *investment));
+            }
+            EquityEventKind::Buyout {
+                seller,
+                buyer,
+                price,
+            } => {
+                if *price < dec!(0) {
+                    Err("Buyout price cannot be negative")?;
+                }
+                let seller_idx = get_owner_idx(owners, seller)?;
+                let seller_investment = owners.remove(seller_idx).get_investment();
+
+                if buyer.is_none() && !owners.is_empty() {
+                    let ratios = owners
+                        .iter()
+                        .map(|owner| (owner.get_name().to_string(), owner.get_investment()))
+                        .collect::<Vec<(String, Decimal)>>();
+                    let bonuses = split_by_ratios(owners, &ratios, seller_investment - price)?;
+
+                    for (owner, bonus) in owners.iter_mut().zip(bonuses) {
+                        let investment = owner.get_investment() + bonus;
+                        if investment <= dec!(0) {
+                            Err("Buyout price is larger than the remaining owners' capital")?;
+                        }
+                        *owner = Owner::new(owner.get_name().to_string(), investment);
+                    }
+                }
+
+                if let Some(buyer) = buyer {
+                    match owners
+                        .iter()
+                        .position(|existing| existing.get_name() == buyer)
+                    {
+                        Some(buyer_idx) => {
+                            owners[buyer_idx] = Owner::new(
+                                buyer.clone(),
+                                owners[buyer_idx].get_investment() + seller_investment,
+                            )
+                        }
+                        None => owners.push(Owner::new(buyer.clone(), seller_investment)),
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn get_owner_idx(owners: &[Owner], name: &str) -> Result<usize, Box<dyn Error>> {
+    match owners.iter().position(|owner| owner.get_name() == name) {
+        Some(idx) => Ok(idx),
+        None => Err(format!("Owner {} does not exist", name))?,
+    }
+}
@@ -0,0 +1,203 @@
+#[cfg(test)]
+use crate::stock_market::generate_utc_date_from_date_string;
+use crate::stock_market::{business_organization::*, equity_event::*};
+use rust_decimal_macros::dec;
+
+fn generate_corporation() -> Corporation {
+    let owners = vec![
+        Owner::new("Mark".to_string(), dec!(60000)),
+        Owner::new("Ben".to_string(), dec!(40000)),
+    ];
+    Corporation::new(owners, Some("KamoteCorp".to_string()), Some(100_000))
+}
+
+#[test]
+fn it_records_a_contribution_and_recomputes_ownership_as_of_a_date() {
+    let mut corporation = generate_corporation();
+    corporation
+        .record_equity_event(EquityEvent::new(
+            generate_utc_date_from_date_string("03-01-2023 00:00"),
+            EquityEventKind::Contribution {
+                owner: "Ben".to_string(),
+                amount: dec!(20000),
+            },
+        ))
+        .unwrap();
+
+    let before = corporation
+        .get_ownership_stakes_as_of(generate_utc_date_from_date_string("02-28-2023 00:00"));
+    assert_eq!(before[0].percentage, dec!(60));
+    assert_eq!(before[1].shares, 40_000);
+
+    let after = corporation
+        .get_ownership_stakes_as_of(generate_utc_date_from_date_string("03-01-2023 00:00"));
+    assert_eq!(after[0].percentage, dec!(50));
+    assert_eq!(after[0].shares, 50_000);
+    assert_eq!(after[1].shares, 50_000);
+    assert_eq!(
+        corporation
+            .get_total_investment_as_of(generate_utc_date_from_date_string("03-01-2023 00:00")),
+        dec!(120000)
+    );
+    assert_eq!(corporation.total_investment, dec!(100000));
+}
+
+#[test]
+fn it_admits_a_new_owner_and_dilutes_existing_owners() {
+    let mut corporation = generate_corporation();
+    corporation
+        .record_equity_event(EquityEvent::new(
+            generate_utc_date_from_date_string("06-01-2023 00:00"),
+            EquityEventKind::Admission {
+                owner: "Hello".to_string(),
+                investment: dec!(50000),
+            },
+        ))
+        .unwrap();
+
+    let stakes = corporation
+        .get_ownership_stakes_as_of(generate_utc_date_from_date_string("12-31-2023 00:00"));
+    assert_eq!(stakes.len(), 3);
+    assert_eq!(stakes[0].percentage, dec!(40));
+    assert_eq!(stakes[2].name, "Hello");
+    assert_eq!(stakes[2].shares, 33_333);
+}
+
+#[test]
+fn it_rejects_a_withdrawal_larger_than_the_investment() {
+    let mut corporation = generate_corporation();
+    let result = corporation.record_equity_event(EquityEvent::new(
+        generate_utc_date_from_date_string("03-01-2023 00:00"),
+        EquityEventKind::Withdrawal {
+            owner: "Ben".to_string(),
+            amount: dec!(40001),
+        },
+    ));
+
+    assert!(result.is_err());
+    assert!(corporation.get_equity_events().is_empty());
+}
+
+#[test]
+fn it_rejects_an_earlier_event_that_breaks_later_history() {
+    let mut corporation = generate_corporation();
+    corporation
+        .record_equity_event(EquityEvent::new(
+            generate_utc_date_from_date_string("06-01-2023 00:00"),
+            EquityEventKind::Withdrawal {
+                owner: "Ben".to_string(),
+                amount: dec!(30000),
+            },
+        ))
+        .unwrap();
+
+    let result = corporation.record_equity_event(EquityEvent::new(
+        generate_utc_date_from_date_string("03-01-2023 00:00"),
+        EquityEventKind::Withdrawal {
+            owner: "Ben".to_string(),
+            amount: dec!(20000),
+        },
+    ));
+
+    assert!(result.is_err());
+    assert_eq!(corporation.get_equity_events().len(), 1);
+}
+
+#[test]
+fn it_transfers_a_bought_out_stake_to_the_buyer() {
+    let mut corporation = generate_corporation();
+    corporation
+        .record_equity_event(EquityEvent::new(
+            generate_utc_date_from_date_string("03-01-2023 00:00"),
+            EquityEventKind::Buyout {
+                seller: "Mark".to_string(),
+                buyer: Some("Ben".to_string()),
+                price: dec!(75000),
+            },
+        ))
+        .unwrap();
+
+    let stakes = corporation
+        .get_ownership_stakes_as_of(generate_utc_date_from_date_string("03-01-2023 00:00"));
+    assert_eq!(stakes.len(), 1);
+    assert_eq!(stakes[0].name, "Ben");
+    assert_eq!(stakes[0].investment, dec!(100000));
+    assert_eq!(stakes[0].shares, 100_000);
+}
+
+#[test]
+fn it_redeems_a_stake_without_a_buyer() {
+    let mut corporation = generate_corporation();
+    corporation
+        .record_equity_event(EquityEvent::new(
+            generate_utc_date_from_date_string("03-01-2023 00:00"),
+            EquityEventKind::Buyout {
+                seller: "Ben".to_string(),
+                buyer: None,
+                price: dec!(45000),
+            },
+        ))
+        .unwrap();
+
+    // The 5000 paid above Ben's capital comes out of Mark's capital
+    let date = generate_utc_date_from_date_string("03-01-2023 00:00");
+    let owners = corporation.get_owners_as_of(date);
+    assert_eq!(owners.len(), 1);
+    assert_eq!(owners[0].get_name(), "Mark");
+    assert_eq!(corporation.get_total_investment_as_of(date), dec!(55000));
+}
+
+#[test]
+fn it_credits_a_buyback_below_capital_to_the_remaining_owners() {
+    let mut corporation = generate_corporation();
+    let date = generate_utc_date_from_date_string("03-01-2023 00:00");
+    corporation
+        .record_equity_event(EquityEvent::new(
+            generate_utc_date_from_date_string("02-01-2023 00:00"),
+            EquityEventKind::Admission {
+                owner: "Jane".to_string(),
+                investment: dec!(20000),
+            },
+        ))
+        .unwrap();
+    corporation
+        .record_equity_event(EquityEvent::new(
+            date,
+            EquityEventKind::Buyout {
+                seller: "Ben".to_string(),
+                buyer: None,
+                price: dec!(32000),
+            },
+        ))
+        .unwrap();
+
+    // The 8000 below Ben's capital is split 3:1 between Mark and Jane
+    let owners = corporation.get_owners_as_of(date);
+    assert_eq!(owners[0].get_investment(), dec!(66000));
+    assert_eq!(owners[1].get_investment(), dec!(22000));
+    assert_eq!(corporation.get_total_investment_as_of(date), dec!(88000));
+    assert!(corporation
+        .record_equity_event(EquityEvent::new(
+            date,
+            EquityEventKind::Buyout {
+                seller: "Mark".to_string(),
+                buyer: None,
+                price: dec!(100000),
+            },
+        ))
+        .is_err());
+}
+
+#[test]
+fn it_rejects_events_for_unknown_owners() {
+    let mut corporation = generate_corporation();
+    let result = corporation.record_equity_event(EquityEvent::new(
+        generate_utc_date_from_date_string("03-01-2023 00:00"),
+        EquityEventKind::Contribution {
+            owner: "Hello".to_string(),
+            amount: dec!(1000),
+        },
+    ));
+
+    assert!(result.is_err());
+}
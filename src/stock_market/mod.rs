@@ -1,6 +1,58 @@
-mod business_organization;
+pub mod backtest;
+#[cfg(test)]
+mod backtest_test;
+pub mod business_organization;
 mod business_organization_test;
+pub mod cap_table;
+#[cfg(test)]
+mod cap_table_test;
+pub mod corporate_action;
+#[cfg(test)]
+mod corporate_action_test;
+pub mod corporate_dividend;
+#[cfg(test)]
+mod corporate_dividend_test;
+pub mod correlation;
+#[cfg(test)]
+mod correlation_test;
+pub mod dividend;
+#[cfg(test)]
+mod dividend_test;
+pub mod equity_event;
+#[cfg(test)]
+mod equity_event_test;
+pub mod order_book;
+#[cfg(test)]
+mod order_book_test;
+pub mod partnership_distribution;
+#[cfg(test)]
+mod partnership_distribution_test;
+pub mod portfolio_optimizer;
+#[cfg(test)]
+mod portfolio_optimizer_test;
+pub mod portfolio_performance;
+#[cfg(test)]
+mod portfolio_performance_test;
+pub mod risk;
+#[cfg(test)]
+mod risk_test;
 pub mod stock_market;
 mod stock_market_test;
-mod stock_portfolio;
-mod stock_portfolio_test;
\ No newline at end of file
+pub mod stock_portfolio;
+#[cfg(test)]
+mod stock_portfolio_test;
+pub mod transaction_ledger;
+#[cfg(test)]
+mod transaction_ledger_test;
+pub mod voting_power;
+#[cfg(test)]
+mod voting_power_test;
+
+// Test dates are written as "%m-%d-%Y %H:%M" and read as UTC.
+#[cfg(test)]
+pub(crate) fn generate_utc_date_from_date_string(
+    date_string: &str,
+) -> chrono::DateTime<chrono::Utc> {
+    let day_one = chrono::NaiveDateTime::parse_from_str(date_string, "%m-%d-%Y %H:%M").unwrap();
+    chrono::TimeZone::from_utc_datetime(&chrono::Utc, &day_one)
+}
@@ -0,0 +1,414 @@
+use std::error::Error;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use crate::stock_market::{
+    stock_market::StockData,
+    stock_portfolio::StockPortfolio,
+    transaction_ledger::{Fee, Transaction, TransactionKind},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrderSide {
+    Buy,
+    Sell,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Trail {
+    Amount(Decimal),
+    // Percent of the reference price, e.g. 5 = 5%.
+    Percent(Decimal),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrderType {
+    Market,
+    Limit(Decimal),
+    Stop(Decimal),
+    StopLimit { stop: Decimal, limit: Decimal },
+    TrailingStop(Trail),
+}
+
+// Bars carry no volume, so an order is always filled completely or not at all.
+// Because of that ImmediateOrCancel and FillOrKill behave the same: fill on the first bar or get cancelled.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TimeInForce {
+    Day,
+    GoodTillCancelled,
+    ImmediateOrCancel,
+    FillOrKill,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrderStatus {
+    Open,
+    Filled,
+    Cancelled,
+    Expired,
+    // Not enough cash to buy or not enough shares to sell when it would have filled.
+    Rejected,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderRequest {
+    pub side: OrderSide,
+    pub shares: Decimal,
+    pub order_type: OrderType,
+    pub time_in_force: TimeInForce,
+}
+
+impl OrderRequest {
+    pub fn new(
+        side: OrderSide,
+        shares: Decimal,
+        order_type: OrderType,
+        time_in_force: TimeInForce,
+    ) -> Result<Self, Box<dyn Error>> {
+        if shares <= dec!(0) {
+            Err("Order shares must be positive")?;
+        }
+
+        Ok(Self {
+            side,
+            shares,
+            order_type,
+            time_in_force,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Order {
+    pub id: u64,
+    pub symbol: String,
+    pub submitted: DateTime<Utc>,
+    pub request: OrderRequest,
+    pub status: OrderStatus,
+    // Set once the stop price of a stop-limit order has been touched.
+    pub triggered: bool,
+    // Highest high (sell) or lowest low (buy) seen by a trailing stop.
+    pub trail_reference: Option<Decimal>,
+}
+
+impl Order {
+    // Current stop price of stop, stop-limit and trailing-stop orders.
+    pub fn get_stop_price(&self) -> Option<Decimal> {
+        match self.request.order_type {
+            OrderType::Stop(stop) | OrderType::StopLimit { stop, .. } => Some(stop),
+            OrderType::TrailingStop(trail) => {
+                let reference = self.trail_reference?;
+                let distance = match trail {
+                    Trail::Amount(amount) => amount,
+                    Trail::Percent(percent) => reference * percent / dec!(100),
+                };
+                match self.request.side {
+                    OrderSide::Sell => Some(reference - distance),
+                    OrderSide::Buy => Some(reference + distance),
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fill {
+    pub order_id: u64,
+    pub date: DateTime<Utc>,
+    pub symbol: String,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub shares: Decimal,
+    pub price: Decimal,
+}
+
+// Fill rules against a single OHLC bar:
+// - Market: fills at the open.
+// - Limit: fills at the open when the open is already at or better than the limit,
+//   otherwise at the limit when the bar's range reaches it.
+// - Stop / trailing stop: fills at the open when the bar gaps through the stop,
+//   otherwise at the stop when the bar's range reaches it.
+// - Stop-limit: once the stop is touched the order becomes a limit order. On the triggering bar it only fills
+//   when the open gapped through the stop and satisfies the limit, or the limit is marketable at the stop price.
+// - Trailing stops move their reference with the bar's high (sell) or low (buy) only after the bar is evaluated,
+//   since the order of the high and low within a bar is unknown.
+// - Market, stop and trailing-stop fills are moved against the trader by slippage_percent, limit prices never slip.
+#[derive(Debug)]
+pub struct OrderBook {
+    orders: Vec<Order>,
+    next_id: u64,
+    pub commissions: Vec<Fee>,
+    pub slippage_percent: Decimal,
+}
+
+impl Default for OrderBook {
+    fn default() -> Self {
+        Self::new(vec![], dec!(0))
+    }
+}
+
+impl OrderBook {
+    pub fn new(commissions: Vec<Fee>, slippage_percent: Decimal) -> Self {
+        Self {
+            orders: vec![],
+            next_id: 1,
+            commissions,
+            slippage_percent,
+        }
+    }
+
+    // The request fields are public, so the shares are checked again here.
+    pub fn submit(
+        &mut self,
+        symbol: &str,
+        date: DateTime<Utc>,
+        request: OrderRequest,
+    ) -> Result<u64, Box<dyn Error>> {
+        if request.shares <= dec!(0) {
+            Err("Order shares must be positive")?;
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.orders.push(Order {
+            id,
+            symbol: symbol.to_string(),
+            submitted: date,
+            request,
+            status: OrderStatus::Open,
+            triggered: false,
+            trail_reference: None,
+        });
+        Ok(id)
+    }
+
+    pub fn cancel(&mut self, order_id: u64) -> bool {
+        match self
+            .orders
+            .iter_mut()
+            .find(|order| order.id == order_id && order.status == OrderStatus::Open)
+        {
+            Some(order) => {
+                order.status = OrderStatus::Cancelled;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn get_order(&self, order_id: u64) -> Option<&Order> {
+        self.orders.iter().find(|order| order.id == order_id)
+    }
+
+    pub fn get_open_orders(&self, symbol: &str) -> Vec<&Order> {
+        self.orders
+            .iter()
+            .filter(|order| order.symbol == symbol && order.status == OrderStatus::Open)
+            .collect()
+    }
+
+    // Evaluates the open orders of a symbol against a bar in submission order.
+    // Cash and shares are used up by each fill, orders that cannot be afforded are rejected.
+    pub fn match_bar(
+        &mut self,
+        symbol: &str,
+        stock_data: &StockData,
+        mut cash: Decimal,
+        mut shares_held: Decimal,
+    ) -> Vec<Fill> {
+        let mut fills: Vec<Fill> = vec![];
+        let commissions = self.commissions.clone();
+        let slippage = self.slippage_percent / dec!(100);
+
+        for order in self.orders.iter_mut().filter(|order| {
+            order.symbol == symbol
+                && order.status == OrderStatus::Open
+                && order.submitted <= stock_data.get_date()
+        }) {
+            if let OrderType::TrailingStop(_) = order.request.order_type {
+                if order.trail_reference.is_none() {
+                    order.trail_reference = Some(stock_data.get_open());
+                }
+            }
+
+            match get_fill_price(order, stock_data) {
+                Some(price) => {
+                    let price = match (order.request.order_type, order.request.side) {
+                        (OrderType::Limit(_) | OrderType::StopLimit { .. }, _) => price,
+                        (_, OrderSide::Buy) => price * (dec!(1) + slippage),
+                        (_, OrderSide::Sell) => price * (dec!(1) - slippage),
+                    };
+                    let shares = order.request.shares;
+                    let gross_amount = shares * price;
+                    let is_sell = order.request.side == OrderSide::Sell;
+                    let fees = commissions.iter().fold(dec!(0), |acc, fee| {
+                        acc + fee.get_amount(shares, gross_amount, is_sell)
+                    });
+
+                    let affordable = match order.request.side {
+                        OrderSide::Buy => gross_amount + fees <= cash,
+                        OrderSide::Sell => shares <= shares_held,
+                    };
+                    if !affordable {
+                        order.status = OrderStatus::Rejected;
+                        continue;
+                    }
+
+                    match order.request.side {
+                        OrderSide::Buy => {
+                            cash -= gross_amount + fees;
+                            shares_held += shares;
+                        }
+                        OrderSide::Sell => {
+                            cash += gross_amount - fees;
+                            shares_held -= shares;
+                        }
+                    }
+
+                    order.status = OrderStatus::Filled;
+                    fills.push(Fill {
+                        order_id: order.id,
+                        date: stock_data.get_date(),
+                        symbol: order.symbol.clone(),
+                        side: order.request.side,
+                        order_type: order.request.order_type,
+                        shares,
+                        price,
+                    });
+                }
+                None => {
+                    order.status = match order.request.time_in_force {
+                        TimeInForce::GoodTillCancelled => OrderStatus::Open,
+                        TimeInForce::Day => OrderStatus::Expired,
+                        TimeInForce::ImmediateOrCancel | TimeInForce::FillOrKill => {
+                            OrderStatus::Cancelled
+                        }
+                    };
+
+                    if let Some(reference) = order.trail_reference {
+                        order.trail_reference = Some(match order.request.side {
+                            OrderSide::Sell => reference.max(stock_data.get_high()),
+                            OrderSide::Buy => reference.min(stock_data.get_low()),
+                        });
+                    }
+                }
+            }
+        }
+
+        fills
+    }
+
+    // Matches a bar and records every fill into the portfolio, so holdings and cash follow the order book.
+    pub fn process_bar(
+        &mut self,
+        symbol: &str,
+        stock_data: &StockData,
+        portfolio: &mut StockPortfolio,
+    ) -> Result<Vec<Fill>, Box<dyn Error>> {
+        let fills = self.match_bar(
+            symbol,
+            stock_data,
+            portfolio.get_cash_balance_as_of(stock_data.get_date()),
+            portfolio
+                .get_open_lots_as_of(stock_data.get_date())
+                .iter()
+                .filter(|lot| lot.symbol == symbol)
+                .fold(dec!(0), |acc, lot| acc + lot.shares),
+        );
+
+        for fill in fills.iter() {
+            portfolio.record(fill.to_transaction(self.commissions.clone()))?;
+        }
+
+        Ok(fills)
+    }
+}
+
+impl Fill {
+    pub fn to_transaction(&self, fees: Vec<Fee>) -> Transaction {
+        let kind = match self.side {
+            OrderSide::Buy => TransactionKind::Buy {
+                symbol: self.symbol.clone(),
+                shares: self.shares,
+                price: self.price,
+            },
+            OrderSide::Sell => TransactionKind::Sell {
+                symbol: self.symbol.clone(),
+                shares: self.shares,
+                price: self.price,
+            },
+        };
+        Transaction::new(self.date, kind, fees)
+    }
+}
+
+fn get_fill_price(order: &mut Order, stock_data: &StockData) -> Option<Decimal> {
+    let side = order.request.side;
+    let (open, high, low) = (
+        stock_data.get_open(),
+        stock_data.get_high(),
+        stock_data.get_low(),
+    );
+
+    match order.request.order_type {
+        OrderType::Market => Some(open),
+        OrderType::Limit(limit) => get_limit_fill_price(side, limit, open, high, low),
+        OrderType::Stop(_) | OrderType::TrailingStop(_) => {
+            get_stop_fill_price(side, order.get_stop_price()?, open, high, low)
+        }
+        OrderType::StopLimit { stop, limit } => {
+            if order.triggered {
+                return get_limit_fill_price(side, limit, open, high, low);
+            }
+
+            let stop_fill_price = get_stop_fill_price(side, stop, open, high, low)?;
+            order.triggered = true;
+
+            let marketable = match side {
+                OrderSide::Buy => stop_fill_price <= limit,
+                OrderSide::Sell => stop_fill_price >= limit,
+            };
+            if marketable {
+                Some(stop_fill_price)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+fn get_limit_fill_price(
+    side: OrderSide,
+    limit: Decimal,
+    open: Decimal,
+    high: Decimal,
+    low: Decimal,
+) -> Option<Decimal> {
+    match side {
+        OrderSide::Buy if open <= limit => Some(open),
+        OrderSide::Buy if low <= limit => Some(limit),
+        OrderSide::Sell if open >= limit => Some(open),
+        OrderSide::Sell if high >= limit => Some(limit),
+        _ => None,
+    }
+}
+
+fn get_stop_fill_price(
+    side: OrderSide,
+    stop: Decimal,
+    open: Decimal,
+    high: Decimal,
+    low: Decimal,
+) -> Option<Decimal> {
+    match side {
+        OrderSide::Buy if open >= stop => Some(open),
+        OrderSide::Buy if high >= stop => Some(stop),
+        OrderSide::Sell if open <= stop => Some(open),
+        OrderSide::Sell if low <= stop => Some(stop),
+        _ => None,
+    }
+}
@@ -0,0 +1,410 @@
+#[cfg(test)]
+use crate::stock_market::generate_utc_date_from_date_string;
+use crate::stock_market::order_book::*;
+use crate::stock_market::{
+    backtest::{Backtester, BarContext, Signal, Strategy},
+    stock_market::{StockData, StockInformation},
+    stock_portfolio::StockPortfolio,
+    transaction_ledger::{Fee, Transaction, TransactionKind, TransactionLedger},
+};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+// (open, high, low, close)
+fn generate_stock_data(day: u32, prices: (Decimal, Decimal, Decimal, Decimal)) -> StockData {
+    StockData::new(
+        generate_utc_date_from_date_string(&format!("10-{:02}-2022 00:00", day)),
+        prices.1,
+        prices.2,
+        prices.0,
+        prices.3,
+    )
+}
+
+fn submit(
+    order_book: &mut OrderBook,
+    side: OrderSide,
+    order_type: OrderType,
+    time_in_force: TimeInForce,
+) -> u64 {
+    order_book
+        .submit(
+            "BNCRP",
+            generate_utc_date_from_date_string("10-01-2022 00:00"),
+            OrderRequest::new(side, dec!(10), order_type, time_in_force).unwrap(),
+        )
+        .unwrap()
+}
+
+#[test]
+fn it_fills_market_and_limit_orders() {
+    let mut order_book = OrderBook::new(vec![], dec!(0));
+    let market_id = submit(
+        &mut order_book,
+        OrderSide::Buy,
+        OrderType::Market,
+        TimeInForce::Day,
+    );
+    let limit_id = submit(
+        &mut order_book,
+        OrderSide::Buy,
+        OrderType::Limit(dec!(98)),
+        TimeInForce::Day,
+    );
+    let gapped_limit_id = submit(
+        &mut order_book,
+        OrderSide::Sell,
+        OrderType::Limit(dec!(95)),
+        TimeInForce::Day,
+    );
+    let unreached_limit_id = submit(
+        &mut order_book,
+        OrderSide::Buy,
+        OrderType::Limit(dec!(90)),
+        TimeInForce::Day,
+    );
+
+    let fills = order_book.match_bar(
+        "BNCRP",
+        &generate_stock_data(2, (dec!(100), dec!(105), dec!(97), dec!(101))),
+        dec!(100000),
+        dec!(10),
+    );
+
+    assert_eq!(fills.len(), 3);
+    assert_eq!(fills[0].order_id, market_id);
+    assert_eq!(fills[0].price, dec!(100));
+    assert_eq!(fills[1].order_id, limit_id);
+    assert_eq!(fills[1].price, dec!(98));
+    assert_eq!(fills[2].order_id, gapped_limit_id);
+    assert_eq!(fills[2].price, dec!(100));
+    assert_eq!(
+        order_book.get_order(unreached_limit_id).unwrap().status,
+        OrderStatus::Expired
+    );
+}
+
+#[test]
+fn it_numbers_orders_from_one_with_either_constructor() {
+    let mut order_book = OrderBook::default();
+    let mut new_order_book = OrderBook::new(vec![], dec!(0));
+
+    assert_eq!(
+        submit(
+            &mut order_book,
+            OrderSide::Buy,
+            OrderType::Market,
+            TimeInForce::Day
+        ),
+        1
+    );
+    assert_eq!(
+        submit(
+            &mut new_order_book,
+            OrderSide::Buy,
+            OrderType::Market,
+            TimeInForce::Day
+        ),
+        1
+    );
+}
+
+#[test]
+fn it_rejects_orders_without_positive_shares() {
+    let mut order_book = OrderBook::default();
+    let mut request = OrderRequest::new(
+        OrderSide::Buy,
+        dec!(10),
+        OrderType::Market,
+        TimeInForce::Day,
+    )
+    .unwrap();
+    request.shares = dec!(-10);
+
+    assert!(
+        OrderRequest::new(OrderSide::Buy, dec!(0), OrderType::Market, TimeInForce::Day).is_err()
+    );
+    assert!(OrderRequest::new(
+        OrderSide::Sell,
+        dec!(-10),
+        OrderType::Market,
+        TimeInForce::Day
+    )
+    .is_err());
+    assert!(order_book
+        .submit(
+            "BNCRP",
+            generate_utc_date_from_date_string("10-01-2022 00:00"),
+            request
+        )
+        .is_err());
+    assert!(order_book.get_open_orders("BNCRP").is_empty());
+}
+
+#[test]
+fn it_fills_stop_orders_at_stop_or_gapped_open() {
+    let mut order_book = OrderBook::new(vec![], dec!(0));
+    let stop_id = submit(
+        &mut order_book,
+        OrderSide::Sell,
+        OrderType::Stop(dec!(96)),
+        TimeInForce::GoodTillCancelled,
+    );
+
+    let fills = order_book.match_bar(
+        "BNCRP",
+        &generate_stock_data(2, (dec!(100), dec!(101), dec!(97), dec!(98))),
+        dec!(0),
+        dec!(10),
+    );
+
+    assert!(fills.is_empty());
+    assert_eq!(order_book.get_open_orders("BNCRP").len(), 1);
+
+    let fills = order_book.match_bar(
+        "BNCRP",
+        &generate_stock_data(3, (dec!(94), dec!(95), dec!(90), dec!(92))),
+        dec!(0),
+        dec!(10),
+    );
+
+    assert_eq!(fills[0].order_id, stop_id);
+    assert_eq!(fills[0].price, dec!(94));
+}
+
+#[test]
+fn it_triggers_stop_limit_then_fills_as_limit() {
+    let mut order_book = OrderBook::new(vec![], dec!(0));
+    let stop_limit_id = submit(
+        &mut order_book,
+        OrderSide::Buy,
+        OrderType::StopLimit {
+            stop: dec!(105),
+            limit: dec!(104),
+        },
+        TimeInForce::GoodTillCancelled,
+    );
+
+    // Stop touched, but the limit is below the stop so it cannot fill on the triggering bar
+    let fills = order_book.match_bar(
+        "BNCRP",
+        &generate_stock_data(2, (dec!(100), dec!(106), dec!(99), dec!(105))),
+        dec!(100000),
+        dec!(0),
+    );
+
+    assert!(fills.is_empty());
+    assert!(order_book.get_order(stop_limit_id).unwrap().triggered);
+
+    let fills = order_book.match_bar(
+        "BNCRP",
+        &generate_stock_data(3, (dec!(106), dec!(107), dec!(103), dec!(104))),
+        dec!(100000),
+        dec!(0),
+    );
+
+    assert_eq!(fills[0].price, dec!(104));
+}
+
+#[test]
+fn it_moves_trailing_stop_with_highs() {
+    let mut order_book = OrderBook::new(vec![], dec!(0));
+    let trailing_stop_id = submit(
+        &mut order_book,
+        OrderSide::Sell,
+        OrderType::TrailingStop(Trail::Percent(dec!(10))),
+        TimeInForce::GoodTillCancelled,
+    );
+
+    order_book.match_bar(
+        "BNCRP",
+        &generate_stock_data(2, (dec!(100), dec!(120), dec!(95), dec!(118))),
+        dec!(0),
+        dec!(10),
+    );
+
+    assert_eq!(
+        order_book
+            .get_order(trailing_stop_id)
+            .unwrap()
+            .get_stop_price(),
+        Some(dec!(108))
+    );
+
+    let fills = order_book.match_bar(
+        "BNCRP",
+        &generate_stock_data(3, (dec!(115), dec!(116), dec!(105), dec!(106))),
+        dec!(0),
+        dec!(10),
+    );
+
+    assert_eq!(fills[0].price, dec!(108));
+}
+
+#[test]
+fn it_cancels_immediate_or_cancel_and_rejects_unaffordable_orders() {
+    let mut order_book = OrderBook::new(vec![], dec!(0));
+    let ioc_id = submit(
+        &mut order_book,
+        OrderSide::Buy,
+        OrderType::Limit(dec!(90)),
+        TimeInForce::ImmediateOrCancel,
+    );
+    let fok_id = submit(
+        &mut order_book,
+        OrderSide::Buy,
+        OrderType::Market,
+        TimeInForce::FillOrKill,
+    );
+    let oversold_id = submit(
+        &mut order_book,
+        OrderSide::Sell,
+        OrderType::Market,
+        TimeInForce::GoodTillCancelled,
+    );
+
+    let fills = order_book.match_bar(
+        "BNCRP",
+        &generate_stock_data(2, (dec!(100), dec!(101), dec!(99), dec!(100))),
+        dec!(500),
+        dec!(0),
+    );
+
+    assert!(fills.is_empty());
+    assert_eq!(
+        order_book.get_order(ioc_id).unwrap().status,
+        OrderStatus::Cancelled
+    );
+    assert_eq!(
+        order_book.get_order(fok_id).unwrap().status,
+        OrderStatus::Rejected
+    );
+    assert_eq!(
+        order_book.get_order(oversold_id).unwrap().status,
+        OrderStatus::Rejected
+    );
+}
+
+#[test]
+fn it_records_fills_into_portfolio() {
+    let mut portfolio = StockPortfolio::new(TransactionLedger::new());
+    portfolio
+        .record(Transaction::new(
+            generate_utc_date_from_date_string("10-01-2022 00:00"),
+            TransactionKind::Deposit { amount: dec!(2000) },
+            vec![],
+        ))
+        .unwrap();
+    let mut order_book = OrderBook::new(vec![Fee::Fixed(dec!(5))], dec!(1));
+    submit(
+        &mut order_book,
+        OrderSide::Buy,
+        OrderType::Market,
+        TimeInForce::Day,
+    );
+
+    let fills = order_book
+        .process_bar(
+            "BNCRP",
+            &generate_stock_data(2, (dec!(100), dec!(101), dec!(99), dec!(100))),
+            &mut portfolio,
+        )
+        .unwrap();
+
+    assert_eq!(fills[0].price, dec!(101));
+    assert_eq!(portfolio.get_shares_held("BNCRP"), dec!(10));
+    assert_eq!(portfolio.get_cash_balance(), dec!(985));
+}
+
+struct LimitEntryStrategy {}
+
+impl Strategy for LimitEntryStrategy {
+    fn on_bar(&mut self, context: &BarContext) -> Signal {
+        match context.idx {
+            0 => Signal::Submit(
+                OrderRequest::new(
+                    OrderSide::Buy,
+                    dec!(10),
+                    OrderType::Limit(dec!(95)),
+                    TimeInForce::GoodTillCancelled,
+                )
+                .unwrap(),
+            ),
+            1 => Signal::Submit(
+                OrderRequest::new(
+                    OrderSide::Sell,
+                    dec!(10),
+                    OrderType::Limit(dec!(110)),
+                    TimeInForce::GoodTillCancelled,
+                )
+                .unwrap(),
+            ),
+            _ => Signal::Hold,
+        }
+    }
+}
+
+#[test]
+fn it_backtests_strategy_submitting_orders() {
+    let stock_data_series = vec![
+        generate_stock_data(1, (dec!(100), dec!(100), dec!(100), dec!(100))),
+        generate_stock_data(2, (dec!(99), dec!(100), dec!(94), dec!(96))),
+        generate_stock_data(3, (dec!(100), dec!(105), dec!(99), dec!(104))),
+        generate_stock_data(4, (dec!(106), dec!(112), dec!(105), dec!(111))),
+    ];
+    let stock_information = StockInformation::new(
+        "BenCorpo".to_string(),
+        "BNCRP".to_string(),
+        stock_data_series,
+    );
+    let backtester = Backtester::new(dec!(1000), dec!(0), vec![]);
+
+    let result = backtester
+        .run(&stock_information, &mut LimitEntryStrategy {})
+        .unwrap();
+
+    assert_eq!(result.trades.len(), 1);
+    assert_eq!(result.trades[0].entry_price, dec!(95));
+    assert_eq!(result.trades[0].exit_price, dec!(110));
+    assert_eq!(result.trades[0].profit, dec!(150));
+}
+
+struct FractionalEntryStrategy {}
+
+impl Strategy for FractionalEntryStrategy {
+    fn on_bar(&mut self, context: &BarContext) -> Signal {
+        match context.idx {
+            0 => Signal::Submit(
+                OrderRequest::new(
+                    OrderSide::Buy,
+                    dec!(10.5),
+                    OrderType::Limit(dec!(95)),
+                    TimeInForce::GoodTillCancelled,
+                )
+                .unwrap(),
+            ),
+            _ => Signal::Hold,
+        }
+    }
+}
+
+#[test]
+fn it_backtests_order_fills_at_the_size_filled_by_the_order_book() {
+    let stock_data_series = vec![
+        generate_stock_data(1, (dec!(100), dec!(100), dec!(100), dec!(100))),
+        generate_stock_data(2, (dec!(99), dec!(100), dec!(94), dec!(96))),
+    ];
+    let stock_information = StockInformation::new(
+        "BenCorpo".to_string(),
+        "BNCRP".to_string(),
+        stock_data_series,
+    );
+    let backtester = Backtester::new(dec!(1000), dec!(0), vec![]);
+
+    let result = backtester
+        .run(&stock_information, &mut FractionalEntryStrategy {})
+        .unwrap();
+
+    assert_eq!(result.portfolio.get_shares_held("BNCRP"), dec!(10.5));
+    assert_eq!(result.portfolio.get_cash_balance(), dec!(2.5));
+}
@@ -0,0 +1,141 @@
+use std::error::Error;
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use crate::stock_market::business_organization::{
+    get_named_amount, split_by_ratios, AllocationRule, Owner,
+};
+
+// residual is the partner's share of what is left after salaries and interest, negative when they exceed the income.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartnerAllocation {
+    pub name: String,
+    pub beginning_capital: Decimal,
+    pub salary: Decimal,
+    pub interest: Decimal,
+    pub residual: Decimal,
+    pub total: Decimal,
+    pub drawings: Decimal,
+    pub ending_capital: Decimal,
+    // Set when the ending capital is negative and the partner owes the partnership.
+    pub capital_deficit: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllocationStatement {
+    pub net_income: Decimal,
+    pub total_salaries: Decimal,
+    pub total_interest: Decimal,
+    pub residual: Decimal,
+    pub allocations: Vec<PartnerAllocation>,
+}
+
+impl AllocationStatement {
+    pub fn get_allocation(&self, name: &str) -> Option<&PartnerAllocation> {
+        self.allocations
+            .iter()
+            .find(|allocation| allocation.name == name)
+    }
+
+    pub fn get_partners_with_capital_deficit(&self) -> Vec<&PartnerAllocation> {
+        self.allocations
+            .iter()
+            .filter(|allocation| allocation.capital_deficit)
+            .collect()
+    }
+}
+
+impl AllocationRule {
+    // Splits a period's net income (negative for a loss) into salaries, interest on beginning capital (the owner's
+    // investment) and residual shares. Amounts are rounded to cents, the rounding difference of the residual goes to
+    // the partner with the largest residual ratio so the allocations add up to net_income.
+    pub fn get_allocation_statement(
+        &self,
+        owners: &[Owner],
+        net_income: Decimal,
+        drawings: &[(String, Decimal)],
+    ) -> Result<AllocationStatement, Box<dyn Error>> {
+        if owners.is_empty() {
+            Err("No owners to allocate to")?;
+        }
+
+        let (salaries, interest_rate, residual_ratios) = match self {
+            AllocationRule::CapitalRatio => (
+                vec![],
+                dec!(0),
+                owners
+                    .iter()
+                    .map(|owner| (owner.get_name().to_string(), owner.get_investment()))
+                    .collect(),
+            ),
+            AllocationRule::FixedRatio(ratios) => (vec![], dec!(0), ratios.clone()),
+            AllocationRule::SalaryPlusInterest {
+                salaries,
+                interest_rate,
+                residual_ratios,
+            } => (salaries.clone(), *interest_rate, residual_ratios.clone()),
+        };
+
+        let owner_salaries = owners
+            .iter()
+            .map(|owner| get_named_amount(&salaries, owner.get_name()).round_dp(2))
+            .collect::<Vec<Decimal>>();
+        let owner_interests = owners
+            .iter()
+            .map(|owner| (owner.get_investment() * interest_rate / dec!(100)).round_dp(2))
+            .collect::<Vec<Decimal>>();
+        let total_salaries = owner_salaries
+            .iter()
+            .fold(dec!(0), |acc, salary| acc + salary);
+        let total_interest = owner_interests
+            .iter()
+            .fold(dec!(0), |acc, interest| acc + interest);
+        let residual = net_income - total_salaries - total_interest;
+
+        let mut owner_residuals = split_by_ratios(owners, &residual_ratios, residual)?
+            .into_iter()
+            .map(|amount| amount.round_dp(2))
+            .collect::<Vec<Decimal>>();
+        let rounding_difference = residual
+            - owner_residuals
+                .iter()
+                .fold(dec!(0), |acc, amount| acc + amount);
+        if let Some(idx) = (0..owners.len())
+            .rev()
+            .max_by_key(|idx| get_named_amount(&residual_ratios, owners[*idx].get_name()))
+        {
+            owner_residuals[idx] += rounding_difference;
+        }
+
+        let allocations = owners
+            .iter()
+            .enumerate()
+            .map(|(idx, owner)| {
+                let total = owner_salaries[idx] + owner_interests[idx] + owner_residuals[idx];
+                let owner_drawings = get_named_amount(drawings, owner.get_name());
+                let ending_capital = owner.get_investment() + total - owner_drawings;
+
+                PartnerAllocation {
+                    name: owner.get_name().to_string(),
+                    beginning_capital: owner.get_investment(),
+                    salary: owner_salaries[idx],
+                    interest: owner_interests[idx],
+                    residual: owner_residuals[idx],
+                    total,
+                    drawings: owner_drawings,
+                    ending_capital,
+                    capital_deficit: ending_capital < dec!(0),
+                }
+            })
+            .collect();
+
+        Ok(AllocationStatement {
+            net_income,
+            total_salaries,
+            total_interest,
+            residual,
+            allocations,
+        })
+    }
+}
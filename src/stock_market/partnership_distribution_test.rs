@@ -0,0 +1,128 @@
+#[cfg(test)]
+use crate::stock_market::business_organization::{AllocationRule, GeneralPartnership, Owner};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn generate_partners() -> Vec<Owner> {
+    vec![
+        Owner::new("Mark".to_string(), dec!(60000)),
+        Owner::new("Ben".to_string(), dec!(40000)),
+    ]
+}
+
+#[test]
+fn it_gets_allocation_statement_with_salaries_interest_and_residual() {
+    let partnership = GeneralPartnership::new(
+        generate_partners(),
+        AllocationRule::SalaryPlusInterest {
+            salaries: vec![
+                ("Mark".to_string(), dec!(5000)),
+                ("Ben".to_string(), dec!(3000)),
+            ],
+            interest_rate: dec!(10),
+            residual_ratios: vec![("Mark".to_string(), dec!(1)), ("Ben".to_string(), dec!(1))],
+        },
+        None,
+    );
+
+    let statement = partnership
+        .get_allocation_statement(
+            dec!(30000),
+            &[
+                ("Mark".to_string(), dec!(10000)),
+                ("Ben".to_string(), dec!(2000)),
+            ],
+        )
+        .unwrap();
+    let mark = statement.get_allocation("Mark").unwrap();
+
+    assert_eq!(statement.total_salaries, dec!(8000));
+    assert_eq!(statement.total_interest, dec!(10000));
+    assert_eq!(statement.residual, dec!(12000));
+    assert_eq!(mark.salary, dec!(5000));
+    assert_eq!(mark.interest, dec!(6000));
+    assert_eq!(mark.residual, dec!(6000));
+    assert_eq!(mark.total, dec!(17000));
+    assert_eq!(mark.ending_capital, dec!(67000));
+    assert_eq!(statement.get_allocation("Ben").unwrap().total, dec!(13000));
+}
+
+#[test]
+fn it_gets_allocation_statement_when_salaries_and_interest_exceed_income() {
+    let partnership = GeneralPartnership::new(
+        generate_partners(),
+        AllocationRule::SalaryPlusInterest {
+            salaries: vec![
+                ("Mark".to_string(), dec!(5000)),
+                ("Ben".to_string(), dec!(3000)),
+            ],
+            interest_rate: dec!(10),
+            residual_ratios: vec![("Mark".to_string(), dec!(3)), ("Ben".to_string(), dec!(1))],
+        },
+        None,
+    );
+
+    let statement = partnership
+        .get_allocation_statement(dec!(10000), &[])
+        .unwrap();
+    let residuals = statement
+        .allocations
+        .iter()
+        .map(|allocation| allocation.residual)
+        .collect::<Vec<Decimal>>();
+
+    assert_eq!(statement.residual, dec!(-8000));
+    assert_eq!(residuals, vec![dec!(-6000), dec!(-2000)]);
+    assert_eq!(statement.get_allocation("Mark").unwrap().total, dec!(5000));
+    assert_eq!(statement.get_allocation("Ben").unwrap().total, dec!(5000));
+}
+
+#[test]
+fn it_gets_allocation_statement_rounded_to_net_income() {
+    let partners = vec![
+        Owner::new("Mark".to_string(), dec!(100)),
+        Owner::new("Benedict".to_string(), dec!(100)),
+        Owner::new("Ben".to_string(), dec!(100)),
+    ];
+    let partnership = GeneralPartnership::new(partners, AllocationRule::CapitalRatio, None);
+
+    let statement = partnership
+        .get_allocation_statement(dec!(100), &[])
+        .unwrap();
+    let totals = statement
+        .allocations
+        .iter()
+        .map(|allocation| allocation.total)
+        .collect::<Vec<Decimal>>();
+
+    assert_eq!(totals, vec![dec!(33.34), dec!(33.33), dec!(33.33)]);
+}
+
+#[test]
+fn it_gets_allocation_statement_with_capital_deficits() {
+    let partners = vec![
+        Owner::new("Mark".to_string(), dec!(1000)),
+        Owner::new("Ben".to_string(), dec!(4000)),
+    ];
+    let partnership = GeneralPartnership::new(
+        partners,
+        AllocationRule::FixedRatio(vec![
+            ("Mark".to_string(), dec!(1)),
+            ("Ben".to_string(), dec!(1)),
+        ]),
+        None,
+    );
+
+    let statement = partnership
+        .get_allocation_statement(dec!(-5000), &[("Ben".to_string(), dec!(500))])
+        .unwrap();
+    let partners_with_capital_deficit = statement.get_partners_with_capital_deficit();
+
+    assert_eq!(partners_with_capital_deficit.len(), 1);
+    assert_eq!(partners_with_capital_deficit[0].name, "Mark");
+    assert_eq!(partners_with_capital_deficit[0].ending_capital, dec!(-1500));
+    assert_eq!(
+        statement.get_allocation("Ben").unwrap().ending_capital,
+        dec!(1000)
+    );
+}
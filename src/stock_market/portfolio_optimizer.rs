@@ -0,0 +1,491 @@
+use std::{error::Error, fs};
+
+use chrono::Utc;
+use plotters::{
+    prelude::{BitMapBackend, ChartBuilder, Circle, IntoDrawingArea, PathElement},
+    series::LineSeries,
+    style::{full_palette::ORANGE, Color, IntoFont, BLACK, BLUE, GREEN, RED, WHITE},
+};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+use rust_decimal_macros::dec;
+
+use crate::stock_market::{
+    correlation::Correlation, risk::TRADING_DAYS_PER_YEAR, stock_market::StockInformation,
+};
+
+// Weights are fractions of the portfolio (0.25 = 25%), long-only is min 0 and max 1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WeightBounds {
+    pub min: Decimal,
+    pub max: Decimal,
+}
+
+impl WeightBounds {
+    pub fn new(min: Decimal, max: Decimal) -> Self {
+        Self { min, max }
+    }
+
+    pub fn long_only() -> Self {
+        Self {
+            min: dec!(0),
+            max: dec!(1),
+        }
+    }
+}
+
+// Expected return and volatility are annualized percents.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OptimizedPortfolio {
+    pub symbols: Vec<String>,
+    pub weights: Vec<Decimal>,
+    pub expected_return: Decimal,
+    pub volatility: Decimal,
+}
+
+impl OptimizedPortfolio {
+    pub fn get_weight(&self, symbol: &str) -> Option<Decimal> {
+        let idx = self.symbols.iter().position(|s| s == symbol)?;
+        Some(self.weights[idx])
+    }
+
+    pub fn get_sharpe_ratio(&self, risk_free_rate: Decimal) -> Option<Decimal> {
+        if self.volatility == dec!(0) {
+            return None;
+        }
+        Some((self.expected_return - risk_free_rate) / self.volatility)
+    }
+}
+
+#[derive(Debug)]
+pub struct PortfolioOptimizer {
+    symbols: Vec<String>,
+    expected_returns: Vec<f64>,
+    covariance: Vec<Vec<f64>>,
+    bounds: Vec<(f64, f64)>,
+}
+
+impl PortfolioOptimizer {
+    pub fn new(
+        symbols: Vec<String>,
+        expected_returns: Vec<Decimal>,
+        covariance: Vec<Vec<Decimal>>,
+        bounds: Option<WeightBounds>,
+    ) -> Result<Self, Box<dyn Error>> {
+        let symbols_len = symbols.len();
+
+        if symbols_len == 0 {
+            Err("Insufficient symbols length")?;
+        } else if expected_returns.len() != symbols_len
+            || covariance.len() != symbols_len
+            || covariance.iter().any(|row| row.len() != symbols_len)
+        {
+            Err("Expected returns and covariance matrix must match the symbols length")?;
+        }
+
+        let bounds = bounds.unwrap_or(WeightBounds::long_only());
+        let mut optimizer = Self {
+            symbols,
+            expected_returns: expected_returns
+                .iter()
+                .map(|value| value.to_f64().unwrap())
+                .collect(),
+            covariance: covariance
+                .iter()
+                .map(|row| row.iter().map(|value| value.to_f64().unwrap()).collect())
+                .collect(),
+            bounds: vec![],
+        };
+        optimizer.bounds = vec![optimizer.to_f64_bounds(bounds)?; symbols_len];
+        optimizer.validate_bounds()?;
+
+        Ok(optimizer)
+    }
+
+    // Annualizes the mean and covariance of daily percent returns over the dates traded by every symbol.
+    pub fn from_stock_informations(
+        stock_informations: &[StockInformation],
+        bounds: Option<WeightBounds>,
+    ) -> Result<Self, Box<dyn Error>> {
+        let aligned_returns = match Correlation::get_aligned_returns(stock_informations) {
+            Some(aligned_returns) => aligned_returns,
+            None => Err("Insufficient stock data series length")?,
+        };
+        let covariance_matrix = match Correlation::get_covariance_matrix(stock_informations) {
+            Some(covariance_matrix) => covariance_matrix,
+            None => Err("Insufficient stock data series length")?,
+        };
+        let periods = Decimal::from(TRADING_DAYS_PER_YEAR);
+
+        let expected_returns = aligned_returns
+            .returns
+            .iter()
+            .map(|returns| {
+                let sum = returns.iter().fold(dec!(0), |acc, value| acc + value);
+                (sum / Decimal::from(returns.len())) * periods
+            })
+            .collect();
+        let covariance = covariance_matrix
+            .values
+            .iter()
+            .map(|row| row.iter().map(|value| value * periods).collect())
+            .collect();
+
+        Self::new(
+            covariance_matrix.symbols,
+            expected_returns,
+            covariance,
+            bounds,
+        )
+    }
+
+    pub fn set_bounds(&mut self, symbol: &str, bounds: WeightBounds) -> Result<(), Box<dyn Error>> {
+        let idx = match self.symbols.iter().position(|s| s == symbol) {
+            Some(idx) => idx,
+            None => Err(format!("Symbol {} not found", symbol))?,
+        };
+        let previous_bounds = self.bounds[idx];
+        self.bounds[idx] = self.to_f64_bounds(bounds)?;
+
+        if let Err(err) = self.validate_bounds() {
+            self.bounds[idx] = previous_bounds;
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    pub fn get_minimum_variance(&self) -> OptimizedPortfolio {
+        self.to_optimized_portfolio(&self.solve(0.0))
+    }
+
+    // Searches along the efficient frontier, where the Sharpe ratio has a single peak.
+    pub fn get_maximum_sharpe(&self, risk_free_rate: Decimal) -> OptimizedPortfolio {
+        let risk_free_rate = risk_free_rate.to_f64().unwrap();
+        let sharpe_ratio = |risk_tolerance: f64| {
+            let weights = self.solve(risk_tolerance);
+            let volatility = self.get_variance(&weights).sqrt();
+            if volatility == 0.0 {
+                f64::MIN
+            } else {
+                (self.get_return(&weights) - risk_free_rate) / volatility
+            }
+        };
+
+        let golden_ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+        let (mut low, mut high) = (0.0, self.get_max_risk_tolerance());
+        for _ in 0..60 {
+            let left = high - golden_ratio * (high - low);
+            let right = low + golden_ratio * (high - low);
+            // Past the highest reachable return the ratio is flat, ties keep the lower risk tolerance.
+            if sharpe_ratio(left) + 1e-12 < sharpe_ratio(right) {
+                low = left;
+            } else {
+                high = right;
+            }
+        }
+
+        self.to_optimized_portfolio(&self.solve((low + high) / 2.0))
+    }
+
+    // Minimum variance weights reaching the target annual return, None when the bounds cannot reach it.
+    pub fn get_target_return(&self, target_return: Decimal) -> Option<OptimizedPortfolio> {
+        let target_return = target_return.to_f64()?;
+        let tolerance = 1e-6;
+
+        let minimum_variance_weights = self.solve(0.0);
+        if self.get_return(&minimum_variance_weights) >= target_return - tolerance {
+            return Some(self.to_optimized_portfolio(&minimum_variance_weights));
+        }
+
+        let max_risk_tolerance = self.get_max_risk_tolerance();
+        if self.get_return(&self.solve(max_risk_tolerance)) < target_return - tolerance {
+            return None;
+        }
+
+        let (mut low, mut high) = (0.0, max_risk_tolerance);
+        for _ in 0..100 {
+            let mid = (low + high) / 2.0;
+            if self.get_return(&self.solve(mid)) < target_return {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        Some(self.to_optimized_portfolio(&self.solve(high)))
+    }
+
+    // Evenly spaced target returns from the minimum variance portfolio up to the highest reachable return.
+    pub fn get_efficient_frontier(&self, points: usize) -> Vec<OptimizedPortfolio> {
+        let minimum_variance = self.get_minimum_variance();
+        let max_return_weights = self.solve(self.get_max_risk_tolerance());
+        let max_return = Decimal::from_f64(self.get_return(&max_return_weights)).unwrap();
+
+        if points < 2 || max_return <= minimum_variance.expected_return {
+            return vec![minimum_variance];
+        }
+
+        let step =
+            (max_return - minimum_variance.expected_return) / Decimal::from(points as u64 - 1);
+
+        (0..points)
+            .filter_map(|point| {
+                self.get_target_return(
+                    minimum_variance.expected_return + step * Decimal::from(point as u64),
+                )
+            })
+            .collect()
+    }
+
+    pub fn show_efficient_frontier(
+        &self,
+        risk_free_rate: Decimal,
+        points: usize,
+        directory: Option<String>,
+        height: Option<u32>,
+        width: Option<u32>,
+    ) -> Result<bool, Box<dyn Error>> {
+        let to_point = |portfolio: &OptimizedPortfolio| {
+            (
+                portfolio.volatility.to_f64().unwrap(),
+                portfolio.expected_return.to_f64().unwrap(),
+            )
+        };
+
+        let frontier_line_data = self
+            .get_efficient_frontier(points)
+            .iter()
+            .map(to_point)
+            .collect::<Vec<(f64, f64)>>();
+        let minimum_variance_point = to_point(&self.get_minimum_variance());
+        let maximum_sharpe_point = to_point(&self.get_maximum_sharpe(risk_free_rate));
+        let asset_points = (0..self.symbols.len())
+            .map(|idx| (self.covariance[idx][idx].sqrt(), self.expected_returns[idx]))
+            .collect::<Vec<(f64, f64)>>();
+
+        let all_points = frontier_line_data.iter().chain(asset_points.iter());
+        let max_volatility = all_points.clone().map(|point| point.0).fold(0.0, f64::max) + 1.0;
+        let min_return = all_points.clone().map(|point| point.1).fold(0.0, f64::min) - 1.0;
+        let max_return = all_points.map(|point| point.1).fold(0.0, f64::max) + 1.0;
+
+        // Setup filepath / directory on which folder to save it
+        let dt = Utc::now();
+        let timestamp: i64 = dt.timestamp();
+
+        let dir = directory.unwrap_or("chart_outputs".to_string());
+
+        fs::create_dir_all(&dir)?;
+
+        let filepath = format!("{}/{}_efficient_frontier.png", &dir, timestamp);
+
+        // Build drawing area
+        let drawing_area =
+            BitMapBackend::new(&filepath, (height.unwrap_or(1024), width.unwrap_or(768)))
+                .into_drawing_area();
+
+        drawing_area.fill(&WHITE)?;
+
+        let font_style = ("sans-serif", 25.0).into_font();
+
+        let mut chart_builder = ChartBuilder::on(&drawing_area);
+        let mut chart = chart_builder
+            .margin(25)
+            .x_label_area_size(50)
+            .y_label_area_size(70)
+            .caption("Efficient Frontier", font_style)
+            .build_cartesian_2d(0.0..max_volatility, min_return..max_return)?;
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .disable_y_mesh()
+            .x_desc("Volatility (%)")
+            .y_desc("Expected Return (%)")
+            .draw()?;
+
+        chart
+            .draw_series(LineSeries::new(frontier_line_data, BLUE.stroke_width(2)))?
+            .label("Efficient Frontier")
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+
+        chart
+            .draw_series(
+                asset_points
+                    .into_iter()
+                    .map(|point| Circle::new(point, 5, GREEN.filled())),
+            )?
+            .label("Assets")
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], GREEN));
+
+        chart
+            .draw_series([Circle::new(minimum_variance_point, 5, RED.filled())])?
+            .label("Minimum Variance")
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+
+        chart
+            .draw_series([Circle::new(maximum_sharpe_point, 5, ORANGE.filled())])?
+            .label("Maximum Sharpe")
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], ORANGE));
+
+        chart
+            .configure_series_labels()
+            .background_style(WHITE.mix(0.8))
+            .border_style(BLACK)
+            .draw()?;
+
+        drawing_area
+            .present()
+            .unwrap_or_else(|_| panic!("Cannot write into {:?}. Directory does not exists.", &dir));
+
+        println!("Efficient Frontier has been saved to {}", filepath);
+
+        Ok(true)
+    }
+
+    fn to_f64_bounds(&self, bounds: WeightBounds) -> Result<(f64, f64), Box<dyn Error>> {
+        if bounds.min > bounds.max {
+            Err("Minimum weight is greater than maximum weight")?;
+        }
+        Ok((bounds.min.to_f64().unwrap(), bounds.max.to_f64().unwrap()))
+    }
+
+    fn validate_bounds(&self) -> Result<(), Box<dyn Error>> {
+        let min_sum = self.bounds.iter().fold(0.0, |acc, bounds| acc + bounds.0);
+        let max_sum = self.bounds.iter().fold(0.0, |acc, bounds| acc + bounds.1);
+
+        if min_sum > 1.0 || max_sum < 1.0 {
+            Err("Weight bounds cannot add up to a fully invested portfolio")?;
+        }
+        Ok(())
+    }
+
+    fn get_return(&self, weights: &[f64]) -> f64 {
+        weights
+            .iter()
+            .zip(self.expected_returns.iter())
+            .fold(0.0, |acc, (weight, expected_return)| {
+                acc + weight * expected_return
+            })
+    }
+
+    fn get_variance(&self, weights: &[f64]) -> f64 {
+        let mut variance = 0.0;
+        for (i, weight_i) in weights.iter().enumerate() {
+            for (j, weight_j) in weights.iter().enumerate() {
+                variance += weight_i * weight_j * self.covariance[i][j];
+            }
+        }
+        variance.max(0.0)
+    }
+
+    // Risk tolerance large enough for the return term to dominate, reaching the highest return allowed by the bounds.
+    fn get_max_risk_tolerance(&self) -> f64 {
+        let max_covariance = self
+            .covariance
+            .iter()
+            .flatten()
+            .fold(0.0, |acc: f64, value| acc.max(value.abs()));
+        let min_return_gap = self
+            .expected_returns
+            .iter()
+            .flat_map(|a| self.expected_returns.iter().map(move |b| (a - b).abs()))
+            .filter(|gap| *gap > 1e-9)
+            .fold(f64::MAX, f64::min);
+
+        if min_return_gap == f64::MAX {
+            return 0.0;
+        }
+        (4.0 * max_covariance / min_return_gap).max(1.0) * 10.0
+    }
+
+    // Minimizes w'Σw - risk_tolerance * μ'w within the weight bounds using projected gradient descent.
+    fn solve(&self, risk_tolerance: f64) -> Vec<f64> {
+        let symbols_len = self.symbols.len();
+        let lipschitz = 2.0
+            * self
+                .covariance
+                .iter()
+                .map(|row| row.iter().fold(0.0, |acc, value| acc + value.abs()))
+                .fold(0.0, f64::max);
+        let step = if lipschitz > 0.0 {
+            1.0 / lipschitz
+        } else {
+            1.0
+        };
+
+        let mut weights = self.project(&vec![1.0 / symbols_len as f64; symbols_len]);
+
+        for _ in 0..5_000 {
+            let gradient = (0..symbols_len)
+                .map(|i| {
+                    let covariance_term = (0..symbols_len)
+                        .fold(0.0, |acc, j| acc + self.covariance[i][j] * weights[j]);
+                    2.0 * covariance_term - risk_tolerance * self.expected_returns[i]
+                })
+                .collect::<Vec<f64>>();
+
+            let next_weights = self.project(
+                &weights
+                    .iter()
+                    .zip(gradient.iter())
+                    .map(|(weight, gradient)| weight - step * gradient)
+                    .collect::<Vec<f64>>(),
+            );
+
+            let change = next_weights
+                .iter()
+                .zip(weights.iter())
+                .fold(0.0, |acc: f64, (a, b)| acc.max((a - b).abs()));
+            weights = next_weights;
+
+            if change < 1e-12 {
+                break;
+            }
+        }
+
+        weights
+    }
+
+    // Euclidean projection onto {Σw = 1, min <= w <= max}, shifting every weight by the same amount.
+    fn project(&self, weights: &[f64]) -> Vec<f64> {
+        let shifted = |shift: f64| {
+            weights
+                .iter()
+                .zip(self.bounds.iter())
+                .map(|(weight, (min, max))| (weight - shift).max(*min).min(*max))
+                .collect::<Vec<f64>>()
+        };
+
+        let max_abs_weight = weights.iter().fold(0.0, |acc: f64, w| acc.max(w.abs()));
+        let (mut low, mut high) = (-max_abs_weight - 2.0, max_abs_weight + 2.0);
+        for _ in 0..100 {
+            let mid = (low + high) / 2.0;
+            if shifted(mid).iter().sum::<f64>() > 1.0 {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        shifted((low + high) / 2.0)
+    }
+
+    fn to_optimized_portfolio(&self, weights: &[f64]) -> OptimizedPortfolio {
+        OptimizedPortfolio {
+            symbols: self.symbols.clone(),
+            weights: weights
+                .iter()
+                .map(|weight| Decimal::from_f64(*weight).unwrap().round_dp(4))
+                .collect(),
+            expected_return: Decimal::from_f64(self.get_return(weights))
+                .unwrap()
+                .round_dp(4),
+            volatility: Decimal::from_f64(self.get_variance(weights).sqrt())
+                .unwrap()
+                .round_dp(4),
+        }
+    }
+}
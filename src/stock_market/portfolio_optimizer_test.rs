@@ -0,0 +1,143 @@
+#[cfg(test)]
+use crate::stock_market::generate_utc_date_from_date_string;
+use crate::stock_market::portfolio_optimizer::*;
+use crate::stock_market::stock_market::{StockData, StockInformation};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn generate_stock_information(symbol: &str, closing_prices: Vec<Decimal>) -> StockInformation {
+    let stock_data_series = closing_prices
+        .into_iter()
+        .enumerate()
+        .map(|(idx, close)| {
+            StockData::new(
+                generate_utc_date_from_date_string(&format!("10-{:02}-2022 00:00", idx + 1)),
+                close,
+                close,
+                close,
+                close,
+            )
+        })
+        .collect();
+    StockInformation::new(symbol.to_string(), symbol.to_string(), stock_data_series)
+}
+
+// Two uncorrelated assets with 10% and 20% volatility.
+fn generate_optimizer(bounds: Option<WeightBounds>) -> PortfolioOptimizer {
+    PortfolioOptimizer::new(
+        vec!["BNCRP".to_string(), "MRKCRP".to_string()],
+        vec![dec!(5), dec!(10)],
+        vec![vec![dec!(100), dec!(0)], vec![dec!(0), dec!(400)]],
+        bounds,
+    )
+    .unwrap()
+}
+
+#[test]
+fn it_gets_minimum_variance_weights() {
+    let optimizer = generate_optimizer(None);
+
+    let minimum_variance = optimizer.get_minimum_variance();
+
+    assert_eq!(minimum_variance.get_weight("BNCRP"), Some(dec!(0.8)));
+    assert_eq!(minimum_variance.get_weight("MRKCRP"), Some(dec!(0.2)));
+    assert_eq!(minimum_variance.expected_return, dec!(6));
+    assert_eq!(minimum_variance.volatility.round_dp(2), dec!(8.94));
+}
+
+#[test]
+fn it_gets_maximum_sharpe_weights() {
+    let optimizer = generate_optimizer(None);
+
+    let maximum_sharpe = optimizer.get_maximum_sharpe(dec!(0));
+
+    assert_eq!(maximum_sharpe.weights[0].round_dp(2), dec!(0.67));
+    assert_eq!(maximum_sharpe.weights[1].round_dp(2), dec!(0.33));
+    assert_eq!(
+        maximum_sharpe
+            .get_sharpe_ratio(dec!(0))
+            .unwrap()
+            .round_dp(3),
+        dec!(0.707)
+    );
+}
+
+#[test]
+fn it_gets_target_return_weights() {
+    let optimizer = generate_optimizer(None);
+
+    let target_return = optimizer.get_target_return(dec!(8)).unwrap();
+
+    assert_eq!(target_return.weights[0].round_dp(3), dec!(0.4));
+    assert_eq!(target_return.weights[1].round_dp(3), dec!(0.6));
+    assert_eq!(optimizer.get_target_return(dec!(11)), None);
+}
+
+#[test]
+fn it_respects_weight_bounds() {
+    let mut optimizer = generate_optimizer(Some(WeightBounds::new(dec!(0), dec!(0.7))));
+
+    let minimum_variance = optimizer.get_minimum_variance();
+
+    assert_eq!(minimum_variance.get_weight("BNCRP"), Some(dec!(0.7)));
+    assert_eq!(minimum_variance.get_weight("MRKCRP"), Some(dec!(0.3)));
+
+    let result = optimizer.set_bounds("MRKCRP", WeightBounds::new(dec!(0), dec!(0.2)));
+
+    match result {
+        Ok(_) => println!("Success"),
+        Err(err) => assert_eq!(
+            err.to_string(),
+            "Weight bounds cannot add up to a fully invested portfolio"
+        ),
+    }
+}
+
+#[test]
+fn it_returns_error_because_covariance_does_not_match_symbols() {
+    let result = PortfolioOptimizer::new(
+        vec!["BNCRP".to_string(), "MRKCRP".to_string()],
+        vec![dec!(5), dec!(10)],
+        vec![vec![dec!(100)]],
+        None,
+    );
+
+    match result {
+        Ok(_) => println!("Success"),
+        Err(err) => assert_eq!(
+            err.to_string(),
+            "Expected returns and covariance matrix must match the symbols length"
+        ),
+    }
+}
+
+#[test]
+fn it_builds_efficient_frontier_from_stock_informations() {
+    let stock_informations = vec![
+        generate_stock_information(
+            "BNCRP",
+            vec![dec!(100), dec!(101), dec!(100.5), dec!(102), dec!(103)],
+        ),
+        generate_stock_information(
+            "MRKCRP",
+            vec![dec!(50), dec!(53), dec!(49), dec!(54), dec!(57)],
+        ),
+    ];
+    let optimizer = PortfolioOptimizer::from_stock_informations(&stock_informations, None).unwrap();
+
+    let efficient_frontier = optimizer.get_efficient_frontier(5);
+
+    assert_eq!(efficient_frontier.len(), 5);
+    for portfolio in efficient_frontier.iter() {
+        let total_weight = portfolio
+            .weights
+            .iter()
+            .fold(dec!(0), |acc, weight| acc + weight);
+        assert_eq!(total_weight.round_dp(2), dec!(1));
+    }
+    assert!(efficient_frontier[4].expected_return > efficient_frontier[0].expected_return);
+
+    let chart = optimizer.show_efficient_frontier(dec!(0), 10, None, None, None);
+
+    assert!(chart.is_ok());
+}
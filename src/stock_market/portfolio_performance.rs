@@ -0,0 +1,303 @@
+use std::{error::Error, fs};
+
+use chrono::{prelude::*, Duration};
+use plotters::{
+    prelude::{BitMapBackend, ChartBuilder, IntoDrawingArea, PathElement, SeriesLabelPosition},
+    series::LineSeries,
+    style::{full_palette::ORANGE, Color, IntoFont, BLACK, BLUE, WHITE},
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use rust_decimal_macros::dec;
+
+use crate::{
+    stock_market::{
+        stock_market::StockInformation, stock_portfolio::StockPortfolio,
+        transaction_ledger::TransactionKind,
+    },
+    time_value_of_money::time_value_of_money::TimeValueOfMoney,
+};
+
+// Returns are expressed in percent, same as net_change_percent of StockData.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PortfolioValuation {
+    pub date: DateTime<Utc>,
+    pub market_value: Decimal,
+    pub cash: Decimal,
+    pub total_value: Decimal,
+    // Deposits minus withdrawals made on this date.
+    pub external_flow: Decimal,
+    pub daily_return: Decimal,
+    pub cumulative_return: Decimal,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchmarkPerformance {
+    pub date: DateTime<Utc>,
+    pub portfolio_cumulative_return: Decimal,
+    pub benchmark_cumulative_return: Decimal,
+    pub excess_return: Decimal,
+}
+
+impl StockPortfolio {
+    // Marks every open lot to market at each date found in the given price series, starting from the first transaction.
+    // Each lot is valued at the last close on or before the date; a lot without any such close makes the series None.
+    pub fn get_valuation_series(
+        &self,
+        stock_informations: &[StockInformation],
+    ) -> Option<Vec<PortfolioValuation>> {
+        let first_transaction_date = self.ledger.get_transactions().first()?.date;
+
+        let mut dates = stock_informations
+            .iter()
+            .flat_map(|stock_information| {
+                stock_information
+                    .get_stock_data_series()
+                    .iter()
+                    .map(|stock_data| stock_data.get_date())
+            })
+            .filter(|date| date >= &first_transaction_date)
+            .collect::<Vec<DateTime<Utc>>>();
+        dates.sort();
+        dates.dedup();
+
+        if dates.is_empty() {
+            return None;
+        }
+
+        let mut valuations: Vec<PortfolioValuation> = Vec::with_capacity(dates.len());
+        let mut previous_total_value = dec!(0);
+        let mut cumulative_growth = dec!(1);
+        let mut previous_date: Option<DateTime<Utc>> = None;
+
+        for date in dates {
+            let market_value =
+                self.get_open_lots_as_of(date)
+                    .iter()
+                    .try_fold(dec!(0), |acc, lot| {
+                        let price = stock_informations
+                            .iter()
+                            .find(|stock_information| stock_information.get_symbol() == lot.symbol)
+                            .and_then(|stock_information| {
+                                stock_information.get_closing_price_as_of(date)
+                            })?;
+                        Some(acc + (lot.shares * price))
+                    })?;
+            let cash = self.get_cash_balance_as_of(date);
+            let total_value = market_value + cash;
+            let external_flow = self.get_external_flow_between(previous_date, date);
+
+            // Flows are assumed to happen at the end of the day, so they are removed before measuring growth.
+            let daily_growth = if previous_total_value > dec!(0) {
+                (total_value - external_flow) / previous_total_value
+            } else {
+                dec!(1)
+            };
+            cumulative_growth *= daily_growth;
+
+            valuations.push(PortfolioValuation {
+                date,
+                market_value,
+                cash,
+                total_value,
+                external_flow,
+                daily_return: ((daily_growth - dec!(1)) * dec!(100)).round_dp(2),
+                cumulative_return: ((cumulative_growth - dec!(1)) * dec!(100)).round_dp(2),
+            });
+
+            previous_total_value = total_value;
+            previous_date = Some(date);
+        }
+
+        Some(valuations)
+    }
+
+    pub fn get_time_weighted_return(
+        &self,
+        stock_informations: &[StockInformation],
+    ) -> Option<Decimal> {
+        let valuations = self.get_valuation_series(stock_informations)?;
+        valuations
+            .last()
+            .map(|valuation| valuation.cumulative_return)
+    }
+
+    // Annualized internal rate of return of deposits, withdrawals and the ending value.
+    pub fn get_money_weighted_return(
+        &self,
+        stock_informations: &[StockInformation],
+    ) -> Option<Decimal> {
+        let valuations = self.get_valuation_series(stock_informations)?;
+        let last_valuation = valuations.last()?;
+
+        let mut cash_flows = self
+            .ledger
+            .get_transactions()
+            .iter()
+            .filter(|transaction| transaction.date <= last_valuation.date)
+            .filter_map(|transaction| match transaction.kind {
+                TransactionKind::Deposit { .. } | TransactionKind::Withdrawal { .. } => {
+                    Some((transaction.date, -transaction.get_cash_flow()))
+                }
+                _ => None,
+            })
+            .collect::<Vec<(DateTime<Utc>, Decimal)>>();
+        cash_flows.push((last_valuation.date, last_valuation.total_value));
+
+        TimeValueOfMoney::get_xirr(&cash_flows).map(|rate| rate.round_dp(2))
+    }
+
+    pub fn get_benchmark_relative_performance(
+        &self,
+        stock_informations: &[StockInformation],
+        benchmark: &StockInformation,
+    ) -> Option<Vec<BenchmarkPerformance>> {
+        let valuations = self.get_valuation_series(stock_informations)?;
+        let base_price = benchmark.get_adjusted_closing_price_as_of(valuations[0].date)?;
+
+        Some(
+            valuations
+                .iter()
+                .map(|valuation| {
+                    let benchmark_price = benchmark
+                        .get_adjusted_closing_price_as_of(valuation.date)
+                        .unwrap_or(base_price);
+                    let benchmark_cumulative_return =
+                        (((benchmark_price - base_price) / base_price) * dec!(100)).round_dp(2);
+
+                    BenchmarkPerformance {
+                        date: valuation.date,
+                        portfolio_cumulative_return: valuation.cumulative_return,
+                        benchmark_cumulative_return,
+                        excess_return: valuation.cumulative_return - benchmark_cumulative_return,
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    pub fn show_performance_chart(
+        &self,
+        stock_informations: &[StockInformation],
+        benchmark: Option<&StockInformation>,
+        directory: Option<String>,
+        height: Option<u32>,
+        width: Option<u32>,
+    ) -> Result<bool, Box<dyn Error>> {
+        let valuations = match self.get_valuation_series(stock_informations) {
+            Some(valuations) => valuations,
+            None => Err("Insufficient valuation series length")?,
+        };
+
+        let portfolio_line_data = valuations
+            .iter()
+            .map(|valuation| {
+                (
+                    valuation.date.date_naive(),
+                    valuation.cumulative_return.to_f64().unwrap(),
+                )
+            })
+            .collect::<Vec<(NaiveDate, f64)>>();
+
+        let benchmark_line_data = match benchmark {
+            Some(benchmark) => self
+                .get_benchmark_relative_performance(stock_informations, benchmark)
+                .unwrap_or_default()
+                .iter()
+                .map(|performance| {
+                    (
+                        performance.date.date_naive(),
+                        performance.benchmark_cumulative_return.to_f64().unwrap(),
+                    )
+                })
+                .collect::<Vec<(NaiveDate, f64)>>(),
+            None => vec![],
+        };
+
+        let returns = portfolio_line_data
+            .iter()
+            .chain(benchmark_line_data.iter())
+            .map(|(_, cumulative_return)| *cumulative_return);
+        let min_return = returns.clone().fold(0.0, f64::min) - 1.0;
+        let max_return = returns.fold(0.0, f64::max) + 1.0;
+
+        let dt = Utc::now();
+        let timestamp: i64 = dt.timestamp();
+
+        let dir = directory.unwrap_or("chart_outputs".to_string());
+
+        fs::create_dir_all(&dir)?;
+
+        let filepath = format!("{}/{}_portfolio_performance.png", &dir, timestamp);
+        let drawing_area =
+            BitMapBackend::new(&filepath, (height.unwrap_or(1024), width.unwrap_or(768)))
+                .into_drawing_area();
+
+        drawing_area.fill(&WHITE)?;
+
+        let (from_date, to_date) = (
+            portfolio_line_data[0].0 - Duration::days(1),
+            portfolio_line_data[portfolio_line_data.len() - 1].0 + Duration::days(1),
+        );
+
+        let font_style = ("sans-serif", 25.0).into_font();
+
+        let mut chart_builder = ChartBuilder::on(&drawing_area);
+        let mut chart = chart_builder
+            .margin(15)
+            .x_label_area_size(40)
+            .y_label_area_size(50)
+            .caption("Portfolio Performance", font_style.clone())
+            .build_cartesian_2d(from_date..to_date, min_return..max_return)?;
+
+        chart
+            .configure_mesh()
+            .light_line_style(WHITE)
+            .y_desc("Cumulative Return (%)")
+            .draw()?;
+
+        chart
+            .draw_series(LineSeries::new(portfolio_line_data, BLUE.stroke_width(2)))?
+            .label("Portfolio")
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+
+        if !benchmark_line_data.is_empty() {
+            let benchmark_label = format!("Benchmark {}", benchmark.unwrap().get_symbol());
+            chart
+                .draw_series(LineSeries::new(benchmark_line_data, ORANGE.stroke_width(2)))?
+                .label(benchmark_label)
+                .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], ORANGE));
+        }
+
+        chart
+            .configure_series_labels()
+            .position(SeriesLabelPosition::UpperLeft)
+            .background_style(WHITE.mix(0.8))
+            .border_style(BLACK)
+            .label_font(font_style)
+            .draw()?;
+
+        drawing_area
+            .present()
+            .unwrap_or_else(|_| panic!("Cannot write into {:?}. Directory does not exists.", &dir));
+
+        println!("Portfolio performance has been saved to {}", filepath);
+
+        Ok(true)
+    }
+
+    fn get_external_flow_between(&self, from: Option<DateTime<Utc>>, to: DateTime<Utc>) -> Decimal {
+        self.ledger
+            .get_transactions()
+            .iter()
+            .filter(|transaction| match from {
+                Some(from) => transaction.date > from && transaction.date <= to,
+                None => transaction.date <= to,
+            })
+            .fold(dec!(0), |acc, transaction| match transaction.kind {
+                TransactionKind::Deposit { .. } | TransactionKind::Withdrawal { .. } => {
+                    acc + transaction.get_cash_flow()
+                }
+                _ => acc,
+            })
+    }
+}
@@ -0,0 +1,168 @@
+#[cfg(test)]
+use crate::stock_market::generate_utc_date_from_date_string;
+use crate::stock_market::stock_portfolio::StockPortfolio;
+use crate::stock_market::{
+    stock_market::{StockData, StockInformation},
+    transaction_ledger::{Transaction, TransactionKind, TransactionLedger},
+};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn generate_stock_information(
+    symbol: &str,
+    closing_prices: Vec<(&str, Decimal)>,
+) -> StockInformation {
+    let stock_data_series = closing_prices
+        .into_iter()
+        .map(|(date_string, close)| {
+            StockData::new(
+                generate_utc_date_from_date_string(date_string),
+                close,
+                close,
+                close,
+                close,
+            )
+        })
+        .collect();
+    StockInformation::new(symbol.to_string(), symbol.to_string(), stock_data_series)
+}
+
+fn generate_portfolio() -> StockPortfolio {
+    let mut portfolio = StockPortfolio::new(TransactionLedger::new());
+    portfolio
+        .record(Transaction::new(
+            generate_utc_date_from_date_string("01-01-2022 00:00"),
+            TransactionKind::Deposit {
+                amount: dec!(10000),
+            },
+            vec![],
+        ))
+        .unwrap();
+    portfolio
+        .record(Transaction::new(
+            generate_utc_date_from_date_string("01-01-2022 00:00"),
+            TransactionKind::Buy {
+                symbol: "BNCRP".to_string(),
+                shares: dec!(100),
+                price: dec!(50),
+            },
+            vec![],
+        ))
+        .unwrap();
+    portfolio
+}
+
+#[test]
+fn it_gets_valuation_series_and_time_weighted_return() {
+    let portfolio = generate_portfolio();
+    let stock_informations = vec![generate_stock_information(
+        "BNCRP",
+        vec![
+            ("01-01-2022 00:00", dec!(50)),
+            ("07-02-2022 00:00", dec!(55)),
+            ("01-01-2023 00:00", dec!(60)),
+        ],
+    )];
+
+    let valuations = portfolio.get_valuation_series(&stock_informations).unwrap();
+
+    assert_eq!(valuations.len(), 3);
+    assert_eq!(valuations[0].total_value, dec!(10000));
+    assert_eq!(valuations[0].external_flow, dec!(10000));
+    assert_eq!(valuations[0].daily_return, dec!(0));
+    assert_eq!(valuations[1].market_value, dec!(5500));
+    assert_eq!(valuations[1].daily_return, dec!(5.00));
+    assert_eq!(valuations[2].cumulative_return, dec!(10.00));
+    assert_eq!(
+        portfolio.get_time_weighted_return(&stock_informations),
+        Some(dec!(10.00))
+    );
+    assert_eq!(
+        portfolio.get_money_weighted_return(&stock_informations),
+        Some(dec!(10.00))
+    );
+}
+
+#[test]
+fn it_excludes_deposits_from_time_weighted_return() {
+    let mut portfolio = generate_portfolio();
+    portfolio
+        .record(Transaction::new(
+            generate_utc_date_from_date_string("07-02-2022 00:00"),
+            TransactionKind::Deposit { amount: dec!(5000) },
+            vec![],
+        ))
+        .unwrap();
+    let stock_informations = vec![generate_stock_information(
+        "BNCRP",
+        vec![
+            ("01-01-2022 00:00", dec!(50)),
+            ("07-02-2022 00:00", dec!(55)),
+        ],
+    )];
+
+    let valuations = portfolio.get_valuation_series(&stock_informations).unwrap();
+
+    assert_eq!(valuations[1].total_value, dec!(15500));
+    assert_eq!(valuations[1].cumulative_return, dec!(5.00));
+}
+
+#[test]
+fn it_gets_benchmark_relative_performance() {
+    let portfolio = generate_portfolio();
+    let stock_informations = vec![generate_stock_information(
+        "BNCRP",
+        vec![
+            ("01-01-2022 00:00", dec!(50)),
+            ("01-02-2022 00:00", dec!(55)),
+            ("01-03-2022 00:00", dec!(60)),
+        ],
+    )];
+    let benchmark = generate_stock_information(
+        "PSEI",
+        vec![
+            ("01-01-2022 00:00", dec!(100)),
+            ("01-02-2022 00:00", dec!(102)),
+            ("01-03-2022 00:00", dec!(104)),
+        ],
+    );
+
+    let performance = portfolio
+        .get_benchmark_relative_performance(&stock_informations, &benchmark)
+        .unwrap();
+
+    assert_eq!(performance.len(), 3);
+    assert_eq!(performance[2].benchmark_cumulative_return, dec!(4.00));
+    assert_eq!(performance[2].excess_return, dec!(6.00));
+
+    let chart =
+        portfolio.show_performance_chart(&stock_informations, Some(&benchmark), None, None, None);
+
+    assert!(chart.is_ok());
+}
+
+#[test]
+fn it_does_not_get_valuation_series_of_empty_portfolio() {
+    let portfolio = StockPortfolio::new(TransactionLedger::new());
+    let stock_informations = vec![generate_stock_information(
+        "BNCRP",
+        vec![("01-01-2022 00:00", dec!(50))],
+    )];
+
+    assert_eq!(portfolio.get_valuation_series(&stock_informations), None);
+}
+
+#[test]
+fn it_does_not_get_valuation_series_without_a_price_of_held_stock() {
+    let portfolio = generate_portfolio();
+    let stock_informations = vec![
+        generate_stock_information("BNCRP", vec![("01-03-2022 00:00", dec!(55))]),
+        generate_stock_information("OTHER", vec![("01-01-2022 00:00", dec!(10))]),
+    ];
+
+    assert_eq!(portfolio.get_valuation_series(&stock_informations), None);
+    assert_eq!(
+        portfolio.get_time_weighted_return(&stock_informations),
+        None
+    );
+}
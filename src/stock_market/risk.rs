@@ -0,0 +1,435 @@
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+use rust_decimal_macros::dec;
+
+use crate::stock_market::{stock_market::StockInformation, stock_portfolio::StockPortfolio};
+
+pub const TRADING_DAYS_PER_YEAR: u32 = 252;
+
+// Returns, rates, volatilities, drawdowns and Value-at-Risk are all expressed in percent.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Drawdown {
+    pub max_drawdown: Decimal,
+    pub peak_idx: usize,
+    pub trough_idx: usize,
+    // Periods from the peak until the series recovers to it, or until the end of the series if it never does.
+    pub duration: usize,
+    pub recovered: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RiskReport {
+    pub annualized_volatility: Option<Decimal>,
+    pub sharpe_ratio: Option<Decimal>,
+    pub sortino_ratio: Option<Decimal>,
+    pub max_drawdown: Option<Drawdown>,
+    pub calmar_ratio: Option<Decimal>,
+    pub beta: Option<Decimal>,
+    pub alpha: Option<Decimal>,
+    pub historical_var: Option<Decimal>,
+    pub parametric_var: Option<Decimal>,
+}
+
+#[derive(Debug)]
+pub struct RiskMetrics {}
+
+impl RiskMetrics {
+    pub fn get_returns(values: &[Decimal]) -> Option<Vec<Decimal>> {
+        if values.len() < 2 || values.contains(&dec!(0)) {
+            return None;
+        }
+
+        Some(
+            values
+                .windows(2)
+                .map(|window| ((window[1] - window[0]) / window[0]) * dec!(100))
+                .collect(),
+        )
+    }
+
+    pub fn get_stock_returns(stock_information: &StockInformation) -> Option<Vec<Decimal>> {
+        Self::get_returns(&get_closing_prices(stock_information))
+    }
+
+    pub fn get_mean(values: &[Decimal]) -> Option<Decimal> {
+        if values.is_empty() {
+            return None;
+        }
+        let sum = values.iter().fold(dec!(0), |acc, value| acc + value);
+        Some(sum / Decimal::from(values.len()))
+    }
+
+    // Sample standard deviation (n - 1).
+    pub fn get_standard_deviation(values: &[Decimal]) -> Option<Decimal> {
+        if values.len() < 2 {
+            return None;
+        }
+        let mean = Self::get_mean(values)?;
+        let squared_diffs = values
+            .iter()
+            .fold(dec!(0), |acc, value| acc + (value - mean) * (value - mean));
+        get_square_root(squared_diffs / Decimal::from(values.len() - 1))
+    }
+
+    pub fn get_annualized_volatility(
+        returns: &[Decimal],
+        periods_per_year: u32,
+    ) -> Option<Decimal> {
+        let standard_deviation = Self::get_standard_deviation(returns)?;
+        Some(standard_deviation * get_square_root(Decimal::from(periods_per_year))?)
+    }
+
+    pub fn get_sharpe_ratio(
+        returns: &[Decimal],
+        risk_free_rate: Decimal,
+        periods_per_year: u32,
+    ) -> Option<Decimal> {
+        let annualized_return = Self::get_mean(returns)? * Decimal::from(periods_per_year);
+        let annualized_volatility = Self::get_annualized_volatility(returns, periods_per_year)?;
+
+        if annualized_volatility == dec!(0) {
+            return None;
+        }
+
+        Some((annualized_return - risk_free_rate) / annualized_volatility)
+    }
+
+    // Like Sharpe but only penalizes periods returning less than the risk free rate.
+    pub fn get_sortino_ratio(
+        returns: &[Decimal],
+        risk_free_rate: Decimal,
+        periods_per_year: u32,
+    ) -> Option<Decimal> {
+        let periods = Decimal::from(periods_per_year);
+        let target_return = risk_free_rate / periods;
+        let annualized_return = Self::get_mean(returns)? * periods;
+
+        let downside_squared = returns.iter().fold(dec!(0), |acc, value| {
+            let shortfall = (value - target_return).min(dec!(0));
+            acc + shortfall * shortfall
+        });
+        let downside_deviation = get_square_root(downside_squared / Decimal::from(returns.len()))?
+            * get_square_root(periods)?;
+
+        if downside_deviation == dec!(0) {
+            return None;
+        }
+
+        Some((annualized_return - risk_free_rate) / downside_deviation)
+    }
+
+    pub fn get_max_drawdown(values: &[Decimal]) -> Option<Drawdown> {
+        if values.len() < 2 {
+            return None;
+        }
+
+        let mut peak_idx = 0;
+        let mut drawdown = Drawdown {
+            max_drawdown: dec!(0),
+            peak_idx: 0,
+            trough_idx: 0,
+            duration: 0,
+            recovered: true,
+        };
+
+        for (idx, value) in values.iter().enumerate() {
+            if value >= &values[peak_idx] {
+                peak_idx = idx;
+                continue;
+            }
+            if values[peak_idx] == dec!(0) {
+                continue;
+            }
+
+            let current_drawdown = ((values[peak_idx] - value) / values[peak_idx]) * dec!(100);
+            if current_drawdown > drawdown.max_drawdown {
+                drawdown.max_drawdown = current_drawdown;
+                drawdown.peak_idx = peak_idx;
+                drawdown.trough_idx = idx;
+            }
+        }
+
+        if drawdown.max_drawdown > dec!(0) {
+            let recovery_idx = values
+                .iter()
+                .enumerate()
+                .skip(drawdown.trough_idx)
+                .find(|(_, value)| value >= &&values[drawdown.peak_idx])
+                .map(|(idx, _)| idx);
+
+            drawdown.recovered = recovery_idx.is_some();
+            drawdown.duration = recovery_idx.unwrap_or(values.len() - 1) - drawdown.peak_idx;
+        }
+
+        Some(drawdown)
+    }
+
+    // Compound annual growth rate of a value series.
+    pub fn get_annualized_return(values: &[Decimal], periods_per_year: u32) -> Option<Decimal> {
+        if values.len() < 2 || values[0] <= dec!(0) {
+            return None;
+        }
+
+        let total_growth = (values[values.len() - 1] / values[0]).to_f64()?;
+        let years = (values.len() - 1) as f64 / periods_per_year as f64;
+        Decimal::from_f64((total_growth.powf(1.0 / years) - 1.0) * 100.0)
+    }
+
+    pub fn get_calmar_ratio(values: &[Decimal], periods_per_year: u32) -> Option<Decimal> {
+        let annualized_return = Self::get_annualized_return(values, periods_per_year)?;
+        let max_drawdown = Self::get_max_drawdown(values)?.max_drawdown;
+
+        if max_drawdown == dec!(0) {
+            return None;
+        }
+
+        Some(annualized_return / max_drawdown)
+    }
+
+    // Beta and annualized Jensen's alpha against benchmark returns of the same periods.
+    pub fn get_beta_alpha(
+        returns: &[Decimal],
+        benchmark_returns: &[Decimal],
+        risk_free_rate: Decimal,
+        periods_per_year: u32,
+    ) -> Option<(Decimal, Decimal)> {
+        if returns.len() != benchmark_returns.len() || returns.len() < 2 {
+            return None;
+        }
+
+        let mean = Self::get_mean(returns)?;
+        let benchmark_mean = Self::get_mean(benchmark_returns)?;
+
+        let (covariance, benchmark_variance) = returns.iter().zip(benchmark_returns.iter()).fold(
+            (dec!(0), dec!(0)),
+            |(covariance, variance), (value, benchmark_value)| {
+                (
+                    covariance + (value - mean) * (benchmark_value - benchmark_mean),
+                    variance
+                        + (benchmark_value - benchmark_mean) * (benchmark_value - benchmark_mean),
+                )
+            },
+        );
+
+        if benchmark_variance == dec!(0) {
+            return None;
+        }
+
+        let periods = Decimal::from(periods_per_year);
+        let risk_free_per_period = risk_free_rate / periods;
+        let beta = covariance / benchmark_variance;
+        let alpha = (mean - risk_free_per_period - beta * (benchmark_mean - risk_free_per_period))
+            * periods;
+
+        Some((beta, alpha))
+    }
+
+    // Loss not exceeded with the given confidence (e.g. 95), read from the observed returns.
+    pub fn get_historical_var(returns: &[Decimal], confidence: Decimal) -> Option<Decimal> {
+        if returns.is_empty() || confidence <= dec!(0) || confidence >= dec!(100) {
+            return None;
+        }
+
+        let mut sorted_returns = returns.to_vec();
+        sorted_returns.sort();
+
+        let tail = (dec!(100) - confidence) / dec!(100) * Decimal::from(sorted_returns.len());
+        let idx = tail.floor().to_usize()?.min(sorted_returns.len() - 1);
+
+        Some((-sorted_returns[idx]).max(dec!(0)))
+    }
+
+    // Value-at-Risk assuming normally distributed returns.
+    pub fn get_parametric_var(returns: &[Decimal], confidence: Decimal) -> Option<Decimal> {
+        if confidence <= dec!(0) || confidence >= dec!(100) {
+            return None;
+        }
+
+        let mean = Self::get_mean(returns)?;
+        let standard_deviation = Self::get_standard_deviation(returns)?;
+        let z_score =
+            Decimal::from_f64(get_inverse_normal_cdf((confidence / dec!(100)).to_f64()?))?;
+
+        Some((z_score * standard_deviation - mean).max(dec!(0)))
+    }
+
+    pub fn get_risk_report(
+        values: &[Decimal],
+        benchmark_values: Option<&[Decimal]>,
+        risk_free_rate: Decimal,
+        confidence: Decimal,
+        periods_per_year: u32,
+    ) -> Option<RiskReport> {
+        let returns = Self::get_returns(values)?;
+        let beta_alpha =
+            benchmark_values
+                .and_then(Self::get_returns)
+                .and_then(|benchmark_returns| {
+                    Self::get_beta_alpha(
+                        &returns,
+                        &benchmark_returns,
+                        risk_free_rate,
+                        periods_per_year,
+                    )
+                });
+
+        Some(RiskReport {
+            annualized_volatility: Self::get_annualized_volatility(&returns, periods_per_year),
+            sharpe_ratio: Self::get_sharpe_ratio(&returns, risk_free_rate, periods_per_year),
+            sortino_ratio: Self::get_sortino_ratio(&returns, risk_free_rate, periods_per_year),
+            max_drawdown: Self::get_max_drawdown(values),
+            calmar_ratio: Self::get_calmar_ratio(values, periods_per_year),
+            beta: beta_alpha.map(|(beta, _)| beta),
+            alpha: beta_alpha.map(|(_, alpha)| alpha),
+            historical_var: Self::get_historical_var(&returns, confidence),
+            parametric_var: Self::get_parametric_var(&returns, confidence),
+        })
+    }
+
+    pub fn get_stock_risk_report(
+        stock_information: &StockInformation,
+        benchmark: Option<&StockInformation>,
+        risk_free_rate: Decimal,
+        confidence: Decimal,
+    ) -> Option<RiskReport> {
+        let closing_prices = get_closing_prices(stock_information);
+        let mut risk_report = Self::get_risk_report(
+            &closing_prices,
+            None,
+            risk_free_rate,
+            confidence,
+            TRADING_DAYS_PER_YEAR,
+        )?;
+
+        // Beta and alpha only compare the dates both series traded on.
+        if let Some(benchmark) = benchmark {
+            let benchmark_data_series = benchmark.get_adjusted_stock_data_series();
+            let (stock_closing_prices, benchmark_closing_prices): (Vec<Decimal>, Vec<Decimal>) =
+                stock_information
+                    .get_adjusted_stock_data_series()
+                    .iter()
+                    .filter_map(|stock_data| {
+                        benchmark_data_series
+                            .iter()
+                            .find(|benchmark_data| {
+                                benchmark_data.get_date() == stock_data.get_date()
+                            })
+                            .map(|benchmark_data| {
+                                (stock_data.get_close(), benchmark_data.get_close())
+                            })
+                    })
+                    .unzip();
+
+            let beta_alpha = Self::get_returns(&stock_closing_prices)
+                .zip(Self::get_returns(&benchmark_closing_prices))
+                .and_then(|(returns, benchmark_returns)| {
+                    Self::get_beta_alpha(
+                        &returns,
+                        &benchmark_returns,
+                        risk_free_rate,
+                        TRADING_DAYS_PER_YEAR,
+                    )
+                });
+            risk_report.beta = beta_alpha.map(|(beta, _)| beta);
+            risk_report.alpha = beta_alpha.map(|(_, alpha)| alpha);
+        }
+
+        Some(risk_report)
+    }
+
+    // Benchmark closing prices are aligned to the portfolio valuation dates.
+    pub fn get_portfolio_risk_report(
+        portfolio: &StockPortfolio,
+        stock_informations: &[StockInformation],
+        benchmark: Option<&StockInformation>,
+        risk_free_rate: Decimal,
+        confidence: Decimal,
+    ) -> Option<RiskReport> {
+        let valuations = portfolio.get_valuation_series(stock_informations)?;
+
+        // Growth of one unit of money, so deposits and withdrawals do not count as returns.
+        let values = valuations
+            .iter()
+            .scan(dec!(100), |value, valuation| {
+                *value *= dec!(1) + valuation.daily_return / dec!(100);
+                Some(*value)
+            })
+            .collect::<Vec<Decimal>>();
+        let benchmark_values = benchmark.and_then(|benchmark| {
+            valuations
+                .iter()
+                .map(|valuation| benchmark.get_adjusted_closing_price_as_of(valuation.date))
+                .collect::<Option<Vec<Decimal>>>()
+        });
+
+        Self::get_risk_report(
+            &values,
+            benchmark_values.as_deref(),
+            risk_free_rate,
+            confidence,
+            TRADING_DAYS_PER_YEAR,
+        )
+    }
+}
+
+// Back-adjusted for corporate actions, so a split does not show up as a return.
+fn get_closing_prices(stock_information: &StockInformation) -> Vec<Decimal> {
+    stock_information
+        .get_adjusted_stock_data_series()
+        .iter()
+        .map(|stock_data| stock_data.get_close())
+        .collect()
+}
+
+fn get_square_root(value: Decimal) -> Option<Decimal> {
+    Decimal::from_f64(value.to_f64()?.sqrt())
+}
+
+// Peter Acklam's rational approximation of the standard normal quantile function.
+fn get_inverse_normal_cdf(p: f64) -> f64 {
+    let a = [
+        -3.969683028665376e1,
+        2.209460984245205e2,
+        -2.759285104469687e2,
+        1.38357751867269e2,
+        -3.066479806614716e1,
+        2.506628277459239,
+    ];
+    let b = [
+        -5.447609879822406e1,
+        1.615858368580409e2,
+        -1.556989798598866e2,
+        6.680131188771972e1,
+        -1.328068155288572e1,
+    ];
+    let c = [
+        -7.784894002430293e-3,
+        -3.223964580411365e-1,
+        -2.400758277161838,
+        -2.549732539343734,
+        4.374664141464968,
+        2.938163982698783,
+    ];
+    let d = [
+        7.784695709041462e-3,
+        3.224671290700398e-1,
+        2.445134137142996,
+        3.754408661907416,
+    ];
+    let p_low = 0.02425;
+
+    if p < p_low {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((c[0] * q + c[1]) * q + c[2]) * q + c[3]) * q + c[4]) * q + c[5])
+            / ((((d[0] * q + d[1]) * q + d[2]) * q + d[3]) * q + 1.0)
+    } else if p <= 1.0 - p_low {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((a[0] * r + a[1]) * r + a[2]) * r + a[3]) * r + a[4]) * r + a[5]) * q
+            / (((((b[0] * r + b[1]) * r + b[2]) * r + b[3]) * r + b[4]) * r + 1.0)
+    } else {
+        -get_inverse_normal_cdf(1.0 - p)
+    }
+}
@@ -0,0 +1,182 @@
+#[cfg(test)]
+use crate::stock_market::generate_utc_date_from_date_string;
+use crate::stock_market::risk::*;
+use crate::stock_market::stock_market::{StockData, StockInformation};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn generate_stock_information(closing_prices: Vec<Decimal>) -> StockInformation {
+    let stock_data_series = closing_prices
+        .into_iter()
+        .enumerate()
+        .map(|(idx, close)| {
+            StockData::new(
+                generate_utc_date_from_date_string(&format!("10-{:02}-2022 00:00", idx + 1)),
+                close,
+                close,
+                close,
+                close,
+            )
+        })
+        .collect();
+    StockInformation::new(
+        "BenCorpo".to_string(),
+        "BNCRP".to_string(),
+        stock_data_series,
+    )
+}
+
+#[test]
+fn it_gets_returns_of_values() {
+    let returns = RiskMetrics::get_returns(&[dec!(100), dec!(120), dec!(90)]).unwrap();
+
+    assert_eq!(returns, vec![dec!(20), dec!(-25)]);
+    assert_eq!(RiskMetrics::get_returns(&[dec!(100)]), None);
+}
+
+#[test]
+fn it_gets_annualized_volatility_and_sharpe_ratio() {
+    let returns = vec![dec!(1), dec!(-1), dec!(1), dec!(-1)];
+
+    let volatility =
+        RiskMetrics::get_annualized_volatility(&returns, TRADING_DAYS_PER_YEAR).unwrap();
+    let sharpe_ratio =
+        RiskMetrics::get_sharpe_ratio(&returns, dec!(0), TRADING_DAYS_PER_YEAR).unwrap();
+
+    assert_eq!(volatility.round_dp(2), dec!(18.33));
+    assert_eq!(sharpe_ratio.round_dp(2), dec!(0));
+}
+
+#[test]
+fn it_gets_sortino_ratio_penalizing_downside_only() {
+    let returns = vec![dec!(2), dec!(-1), dec!(3), dec!(-1)];
+
+    let sortino_ratio = RiskMetrics::get_sortino_ratio(&returns, dec!(0), 4).unwrap();
+
+    // Annualized return 3, downside deviation sqrt(2 / 4) * sqrt(4)
+    assert_eq!(sortino_ratio.round_dp(2), dec!(2.12));
+}
+
+#[test]
+fn it_gets_max_drawdown_and_duration() {
+    let values = vec![dec!(100), dec!(120), dec!(90), dec!(130), dec!(117)];
+
+    let drawdown = RiskMetrics::get_max_drawdown(&values).unwrap();
+
+    assert_eq!(drawdown.max_drawdown, dec!(25));
+    assert_eq!(drawdown.peak_idx, 1);
+    assert_eq!(drawdown.trough_idx, 2);
+    assert_eq!(drawdown.duration, 2);
+    assert!(drawdown.recovered);
+
+    let drawdown = RiskMetrics::get_max_drawdown(&[dec!(100), dec!(80), dec!(90)]).unwrap();
+
+    assert_eq!(drawdown.max_drawdown, dec!(20));
+    assert_eq!(drawdown.duration, 2);
+    assert!(!drawdown.recovered);
+}
+
+#[test]
+fn it_gets_calmar_ratio() {
+    // One year of growth from 100 to 110 with a 20% drawdown along the way
+    let values = vec![dec!(100), dec!(80), dec!(110)];
+
+    let calmar_ratio = RiskMetrics::get_calmar_ratio(&values, 2).unwrap();
+
+    assert_eq!(calmar_ratio.round_dp(2), dec!(0.50));
+}
+
+#[test]
+fn it_gets_beta_and_alpha() {
+    let returns = vec![dec!(2), dec!(-4), dec!(6)];
+    let benchmark_returns = vec![dec!(1), dec!(-2), dec!(3)];
+
+    let (beta, alpha) =
+        RiskMetrics::get_beta_alpha(&returns, &benchmark_returns, dec!(0), TRADING_DAYS_PER_YEAR)
+            .unwrap();
+
+    assert_eq!(beta.round_dp(8), dec!(2));
+    assert_eq!(alpha.round_dp(8), dec!(0));
+}
+
+#[test]
+fn it_gets_historical_and_parametric_value_at_risk() {
+    let returns = vec![dec!(1), dec!(-1), dec!(1), dec!(-1)];
+
+    let historical_var = RiskMetrics::get_historical_var(&returns, dec!(95)).unwrap();
+    let parametric_var = RiskMetrics::get_parametric_var(&returns, dec!(95)).unwrap();
+
+    assert_eq!(historical_var, dec!(1));
+    assert_eq!(parametric_var.round_dp(2), dec!(1.90));
+    assert_eq!(RiskMetrics::get_historical_var(&returns, dec!(100)), None);
+}
+
+#[test]
+fn it_gets_risk_report_of_stock_against_benchmark() {
+    let stock_information =
+        generate_stock_information(vec![dec!(100), dec!(102), dec!(98), dec!(104), dec!(101)]);
+    let benchmark =
+        generate_stock_information(vec![dec!(100), dec!(101), dec!(99), dec!(102), dec!(100)]);
+
+    let risk_report =
+        RiskMetrics::get_stock_risk_report(&stock_information, Some(&benchmark), dec!(2), dec!(95))
+            .unwrap();
+
+    assert!(risk_report.annualized_volatility.unwrap() > dec!(0));
+    assert!(risk_report.beta.unwrap() > dec!(1));
+    assert_eq!(risk_report.max_drawdown.unwrap().peak_idx, 1);
+    assert_eq!(risk_report.historical_var.unwrap().round_dp(2), dec!(3.92));
+}
+
+#[test]
+fn it_aligns_benchmark_on_dates_both_series_traded() {
+    let stock_information =
+        generate_stock_information(vec![dec!(100), dec!(102), dec!(98), dec!(104), dec!(101)]);
+    // Benchmark missing the second trading day, with an extra day the stock did not trade on
+    let gapped_benchmark = StockInformation::new(
+        "BenCorpo".to_string(),
+        "BNCRP".to_string(),
+        [
+            (1, dec!(100)),
+            (3, dec!(99)),
+            (4, dec!(102)),
+            (5, dec!(100)),
+            (9, dec!(90)),
+        ]
+        .into_iter()
+        .map(|(day, close)| {
+            StockData::new(
+                generate_utc_date_from_date_string(&format!("10-{:02}-2022 00:00", day)),
+                close,
+                close,
+                close,
+                close,
+            )
+        })
+        .collect(),
+    );
+    // Closing prices of the dates both series traded on
+    let aligned_stock_information =
+        generate_stock_information(vec![dec!(100), dec!(98), dec!(104), dec!(101)]);
+    let aligned_benchmark =
+        generate_stock_information(vec![dec!(100), dec!(99), dec!(102), dec!(100)]);
+
+    let risk_report = RiskMetrics::get_stock_risk_report(
+        &stock_information,
+        Some(&gapped_benchmark),
+        dec!(2),
+        dec!(95),
+    )
+    .unwrap();
+    let (beta, alpha) = RiskMetrics::get_beta_alpha(
+        &RiskMetrics::get_stock_returns(&aligned_stock_information).unwrap(),
+        &RiskMetrics::get_stock_returns(&aligned_benchmark).unwrap(),
+        dec!(2),
+        TRADING_DAYS_PER_YEAR,
+    )
+    .unwrap();
+
+    assert_eq!(risk_report.beta.unwrap(), beta);
+    assert_eq!(risk_report.alpha.unwrap(), alpha);
+    assert_eq!(risk_report.max_drawdown.unwrap().peak_idx, 1);
+}
@@ -17,6 +17,14 @@ use rust_decimal::{
 };
 use rust_decimal_macros::dec;
 
+use crate::{
+    stock_market::{corporate_action::CorporateAction, dividend::DividendEvent},
+    time_value_of_money::{
+        day_count::DayCountConvention, schedule::SchedulePeriod,
+        time_value_of_money::TimeValueOfMoney,
+    },
+};
+
 // net_change and net_change_percent will be computed.
 #[derive(Debug)]
 pub struct StockData {
@@ -47,6 +55,22 @@ impl StockData {
             net_change_percent: None,
         }
     }
+
+    pub fn get_date(&self) -> DateTime<Utc> {
+        self.date
+    }
+    pub fn get_high(&self) -> Decimal {
+        self.high
+    }
+    pub fn get_low(&self) -> Decimal {
+        self.low
+    }
+    pub fn get_open(&self) -> Decimal {
+        self.open
+    }
+    pub fn get_close(&self) -> Decimal {
+        self.close
+    }
 }
 
 #[derive(Debug)]
@@ -54,6 +78,8 @@ pub struct StockInformation {
     company_name: String,
     symbol: String,
     stock_data_series: Vec<StockData>,
+    dividend_events: Vec<DividendEvent>,
+    corporate_actions: Vec<CorporateAction>,
 }
 
 impl StockInformation {
@@ -62,16 +88,67 @@ impl StockInformation {
             company_name,
             symbol,
             stock_data_series,
+            dividend_events: vec![],
+            corporate_actions: vec![],
         }
     }
 
+    // Keeps the dividend events ordered by ex-dividend date.
+    pub fn add_dividend_event(&mut self, dividend_event: DividendEvent) {
+        let idx = self
+            .dividend_events
+            .iter()
+            .position(|event| event.ex_date > dividend_event.ex_date)
+            .unwrap_or(self.dividend_events.len());
+        self.dividend_events.insert(idx, dividend_event);
+    }
+
+    pub fn get_dividend_events(&self) -> &Vec<DividendEvent> {
+        &self.dividend_events
+    }
+
+    // Keeps the corporate actions ordered by effective date.
+    pub fn add_corporate_action(&mut self, corporate_action: CorporateAction) {
+        let idx = self
+            .corporate_actions
+            .iter()
+            .position(|action| action.date > corporate_action.date)
+            .unwrap_or(self.corporate_actions.len());
+        self.corporate_actions.insert(idx, corporate_action);
+    }
+
+    pub fn get_corporate_actions(&self) -> &Vec<CorporateAction> {
+        &self.corporate_actions
+    }
+
+    pub fn get_company_name(&self) -> &str {
+        &self.company_name
+    }
+    pub fn get_symbol(&self) -> &str {
+        &self.symbol
+    }
+    pub fn get_stock_data_series(&self) -> &Vec<StockData> {
+        &self.stock_data_series
+    }
+
+    // Last known closing price on or before the given date.
+    pub fn get_closing_price_as_of(&self, date: DateTime<Utc>) -> Option<Decimal> {
+        self.stock_data_series
+            .iter()
+            .filter(|stock_data| stock_data.date <= date)
+            .max_by_key(|stock_data| stock_data.date)
+            .map(|stock_data| stock_data.close)
+    }
+
+    // Changes are measured on the series back-adjusted for corporate actions, so a split is not a loss.
     pub fn get_change_of_stock_data_series(&self) -> Option<Vec<StockData>> {
-        if self.stock_data_series.len() == 0 {
+        let stock_data_series = self.get_adjusted_stock_data_series();
+        if stock_data_series.len() == 0 {
             return None;
         }
 
         let mut stock_data_series_with_change: Vec<StockData> = vec![];
-        for (index, stock_data) in self.stock_data_series.iter().enumerate() {
+        for (index, stock_data) in stock_data_series.iter().enumerate() {
             if index == 0 {
                 stock_data_series_with_change.push(StockData {
                     date: stock_data.date,
@@ -83,7 +160,7 @@ impl StockInformation {
                     net_change_percent: Some(dec!(0.0)),
                 });
             } else {
-                let previous_day_close = &self.stock_data_series[index - 1].close;
+                let previous_day_close = &stock_data_series[index - 1].close;
                 let current_day_close = stock_data.close;
 
                 let net_change = current_day_close - previous_day_close;
@@ -123,6 +200,7 @@ impl StockInformation {
             None => None,
         }
     }
+    // Averages the closing prices back-adjusted for corporate actions.
     pub fn get_moving_averages(&self, ma_days: u16) -> Option<Vec<Decimal>> {
         if self.stock_data_series.len() == 0 {
             return None;
@@ -130,7 +208,7 @@ impl StockInformation {
 
         let mut moving_averages: Vec<Decimal> = vec![];
         let closing_prices = self
-            .stock_data_series
+            .get_adjusted_stock_data_series()
             .iter()
             .map(|stock_data| stock_data.close)
             .collect::<Vec<Decimal>>();
@@ -180,7 +258,7 @@ impl StockInformation {
         height: Option<u32>,
         width: Option<u32>,
     ) -> Result<bool, Box<dyn Error>> {
-        let stock_data_series = &self.stock_data_series;
+        let stock_data_series = &self.get_adjusted_stock_data_series();
         if stock_data_series.len() == 0 {
             Err("Insufficient stock data series length")?;
         }
@@ -348,8 +426,67 @@ impl StockInformation {
     pub fn get_yield_rate(dividend_per_share: Decimal, price_per_share: Decimal) -> Decimal {
         ((dividend_per_share / price_per_share) * dec!(100)).round_dp(1)
     }
+    // One period of simple interest, see TimeValueOfMoney for compounding.
     pub fn get_interest_income(interest_rate: Decimal, amount: Decimal) -> Decimal {
-        let interest = interest_rate / dec!(100);
-        interest * amount
+        TimeValueOfMoney::get_simple_interest(interest_rate, amount, dec!(1))
+    }
+    // Annual dividend_per_share accrued over each period of the schedule, paid on its payment date, so a short
+    // stub period pays only its share of the year.
+    pub fn get_scheduled_dividend_income(
+        shares: Decimal,
+        dividend_per_share: Decimal,
+        schedule: &[SchedulePeriod],
+        day_count_convention: DayCountConvention,
+    ) -> Vec<(DateTime<Utc>, Decimal)> {
+        schedule
+            .iter()
+            .map(|period| {
+                (
+                    period.payment_date,
+                    StockInformation::get_dividend_income(
+                        shares,
+                        dividend_per_share,
+                        day_count_convention.get_year_fraction(period.start, period.end),
+                    ),
+                )
+            })
+            .collect()
+    }
+    // Annual interest_rate accrued between two dates under the given day count convention.
+    pub fn get_accrued_interest_income(
+        interest_rate: Decimal,
+        amount: Decimal,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        day_count_convention: DayCountConvention,
+    ) -> Decimal {
+        TimeValueOfMoney::get_simple_interest(
+            interest_rate,
+            amount,
+            day_count_convention.get_year_fraction(start, end),
+        )
+    }
+    // Interest accrued over each period of the schedule, paid on its payment date.
+    pub fn get_scheduled_interest_income(
+        interest_rate: Decimal,
+        amount: Decimal,
+        schedule: &[SchedulePeriod],
+        day_count_convention: DayCountConvention,
+    ) -> Vec<(DateTime<Utc>, Decimal)> {
+        schedule
+            .iter()
+            .map(|period| {
+                (
+                    period.payment_date,
+                    StockInformation::get_accrued_interest_income(
+                        interest_rate,
+                        amount,
+                        period.start,
+                        period.end,
+                        day_count_convention,
+                    ),
+                )
+            })
+            .collect()
     }
 }
@@ -1,4 +1,9 @@
 use crate::stock_market::stock_market::StockData;
+#[cfg(test)]
+use crate::time_value_of_money::{
+    day_count::DayCountConvention,
+    schedule::{BusinessDayConvention, Frequency, Schedule},
+};
 use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
 use rand::Rng;
 use rust_decimal::{
@@ -480,3 +485,116 @@ fn it_gets_interest_income() {
 
     assert_eq!(interest_income, dec!(57));
 }
+
+#[test]
+fn it_gets_scheduled_dividend_income() {
+    let schedule = Schedule::get_periods(
+        generate_utc_date_from_date_string("01-15-2022 00:00"),
+        generate_utc_date_from_date_string("01-15-2023 00:00"),
+        Frequency::Quarterly,
+        BusinessDayConvention::Following,
+        &[],
+    );
+    let dividend_income = StockInformation::get_scheduled_dividend_income(
+        dec!(2000),
+        dec!(2.04),
+        &schedule,
+        DayCountConvention::Thirty360,
+    );
+
+    assert_eq!(dividend_income.len(), 4);
+    assert_eq!(
+        dividend_income[0],
+        (
+            generate_utc_date_from_date_string("04-15-2022 00:00"),
+            dec!(1020)
+        )
+    );
+    assert_eq!(
+        dividend_income
+            .iter()
+            .fold(dec!(0), |acc, (_, amount)| acc + amount),
+        dec!(4080)
+    );
+}
+
+#[test]
+fn it_gets_scheduled_dividend_income_of_a_stub_period() {
+    let schedule = Schedule::get_periods(
+        generate_utc_date_from_date_string("01-15-2022 00:00"),
+        generate_utc_date_from_date_string("12-01-2022 00:00"),
+        Frequency::Quarterly,
+        BusinessDayConvention::Following,
+        &[],
+    );
+    let dividend_income = StockInformation::get_scheduled_dividend_income(
+        dec!(2000),
+        dec!(2.04),
+        &schedule,
+        DayCountConvention::Thirty360,
+    );
+    let total_dividend_income = dividend_income
+        .iter()
+        .fold(dec!(0), |acc, (_, amount)| acc + amount);
+
+    // 10-15 to 12-01 is 46 days under 30/360, not a full quarter
+    assert_eq!(dividend_income.len(), 4);
+    assert_eq!(dividend_income[3].1.round_dp(2), dec!(521.33));
+    assert_eq!(total_dividend_income.round_dp(2), dec!(3581.33));
+    assert_ne!(total_dividend_income, dec!(1020) * dec!(4));
+}
+
+#[test]
+fn it_gets_accrued_interest_income() {
+    let start = generate_utc_date_from_date_string("01-15-2022 00:00");
+    let end = generate_utc_date_from_date_string("07-15-2022 00:00");
+
+    let actual_360_interest_income = StockInformation::get_accrued_interest_income(
+        dec!(5.7),
+        dec!(1000),
+        start,
+        end,
+        DayCountConvention::Actual360,
+    );
+    let thirty_360_interest_income = StockInformation::get_accrued_interest_income(
+        dec!(5.7),
+        dec!(1000),
+        start,
+        end,
+        DayCountConvention::Thirty360,
+    );
+
+    assert_eq!(actual_360_interest_income.round_dp(2), dec!(28.66));
+    assert_eq!(thirty_360_interest_income, dec!(28.5));
+}
+
+#[test]
+fn it_gets_scheduled_interest_income() {
+    let schedule = Schedule::get_periods(
+        generate_utc_date_from_date_string("01-15-2022 00:00"),
+        generate_utc_date_from_date_string("01-15-2023 00:00"),
+        Frequency::Semiannual,
+        BusinessDayConvention::Following,
+        &[],
+    );
+    let interest_income = StockInformation::get_scheduled_interest_income(
+        dec!(6),
+        dec!(1000),
+        &schedule,
+        DayCountConvention::Thirty360,
+    );
+
+    assert_eq!(
+        interest_income,
+        vec![
+            (
+                generate_utc_date_from_date_string("07-15-2022 00:00"),
+                dec!(30)
+            ),
+            (
+                generate_utc_date_from_date_string("01-16-2023 00:00"),
+                dec!(30)
+            ),
+        ]
+    );
+}
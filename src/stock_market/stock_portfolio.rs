@@ -1,10 +1,60 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
-#[derive(Debug)]
-pub struct StockPortfolio {}
+use crate::stock_market::transaction_ledger::{Transaction, TransactionKind, TransactionLedger};
+
+// cost_per_share already includes the fees paid when the lot was bought.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lot {
+    pub symbol: String,
+    pub date: DateTime<Utc>,
+    pub shares: Decimal,
+    pub cost_per_share: Decimal,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CashFlow {
+    pub date: DateTime<Utc>,
+    pub amount: Decimal,
+    pub balance: Decimal,
+}
+
+#[derive(Debug, Default)]
+struct Holdings {
+    lots: Vec<Lot>,
+    net_proceeds: HashMap<String, Decimal>,
+    net_gains: HashMap<String, Decimal>,
+    cash: Decimal,
+    oversold: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct StockPortfolio {
+    pub ledger: TransactionLedger,
+}
 
 impl StockPortfolio {
+    pub fn new(ledger: TransactionLedger) -> Self {
+        Self { ledger }
+    }
+
+    // A sell larger than the shares held at its date is rejected and the ledger is left untouched.
+    pub fn record(&mut self, transaction: Transaction) -> Result<(), Box<dyn Error>> {
+        let mut portfolio = StockPortfolio::new(self.ledger.clone());
+        portfolio.ledger.record(transaction);
+
+        if portfolio.replay(None).oversold {
+            Err("Sell is larger than the shares held")?
+        }
+
+        self.ledger = portfolio.ledger;
+        Ok(())
+    }
+
     pub fn get_capital_gains(selling_price: Decimal, purchase_price: Decimal) -> Decimal {
         selling_price - purchase_price
     }
@@ -12,4 +62,169 @@ impl StockPortfolio {
         let capital_gains = StockPortfolio::get_capital_gains(selling_price, purchase_price);
         (capital_gains / purchase_price) * dec!(100)
     }
+
+    pub fn get_open_lots(&self, symbol: &str) -> Vec<Lot> {
+        self.replay(None)
+            .lots
+            .into_iter()
+            .filter(|lot| lot.symbol == symbol)
+            .collect()
+    }
+
+    pub fn get_shares_held(&self, symbol: &str) -> Decimal {
+        self.get_open_lots(symbol)
+            .iter()
+            .fold(dec!(0), |acc, lot| acc + lot.shares)
+    }
+
+    // Selling proceeds after commissions and taxes.
+    pub fn get_net_proceeds(&self, symbol: &str) -> Decimal {
+        self.replay(None)
+            .net_proceeds
+            .get(symbol)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    // Realized gains of sold shares, matched against bought lots first-in first-out.
+    pub fn get_net_gains(&self, symbol: &str) -> Decimal {
+        self.replay(None)
+            .net_gains
+            .get(symbol)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn get_cash_balance(&self) -> Decimal {
+        self.replay(None).cash
+    }
+
+    pub fn get_open_lots_as_of(&self, date: DateTime<Utc>) -> Vec<Lot> {
+        self.replay(Some(date)).lots
+    }
+
+    pub fn get_cash_balance_as_of(&self, date: DateTime<Utc>) -> Decimal {
+        self.replay(Some(date)).cash
+    }
+
+    pub fn get_cash_flow_history(&self) -> Vec<CashFlow> {
+        let mut balance = dec!(0);
+
+        self.ledger
+            .get_transactions()
+            .iter()
+            .map(|transaction| {
+                let amount = transaction.get_cash_flow();
+                balance += amount;
+
+                CashFlow {
+                    date: transaction.date,
+                    amount,
+                    balance,
+                }
+            })
+            .collect()
+    }
+
+    // Walk the ledger from the start up to (and including) the given date.
+    fn replay(&self, until: Option<DateTime<Utc>>) -> Holdings {
+        let mut holdings = Holdings::default();
+
+        for transaction in self.ledger.get_transactions() {
+            if let Some(until) = until {
+                if transaction.date > until {
+                    break;
+                }
+            }
+
+            holdings.cash += transaction.get_cash_flow();
+
+            match &transaction.kind {
+                TransactionKind::Buy { symbol, shares, .. } => {
+                    if *shares == dec!(0) {
+                        continue;
+                    }
+
+                    let total_cost = -transaction.get_cash_flow();
+                    holdings.lots.push(Lot {
+                        symbol: symbol.to_string(),
+                        date: transaction.date,
+                        shares: *shares,
+                        cost_per_share: total_cost / shares,
+                    });
+                }
+                TransactionKind::Sell { symbol, shares, .. } => {
+                    let net_proceeds = transaction.get_cash_flow();
+                    let mut remaining_shares = *shares;
+                    let mut cost_basis = dec!(0);
+
+                    for lot in holdings.lots.iter_mut().filter(|lot| &lot.symbol == symbol) {
+                        if remaining_shares == dec!(0) {
+                            break;
+                        }
+                        let sold_shares = remaining_shares.min(lot.shares);
+                        cost_basis += sold_shares * lot.cost_per_share;
+                        lot.shares -= sold_shares;
+                        remaining_shares -= sold_shares;
+                    }
+                    holdings.lots.retain(|lot| lot.shares > dec!(0));
+                    if remaining_shares > dec!(0) {
+                        holdings.oversold = true;
+                    }
+
+                    *holdings
+                        .net_proceeds
+                        .entry(symbol.to_string())
+                        .or_insert(dec!(0)) += net_proceeds;
+                    *holdings
+                        .net_gains
+                        .entry(symbol.to_string())
+                        .or_insert(dec!(0)) += net_proceeds - cost_basis;
+                }
+                TransactionKind::Split {
+                    symbol,
+                    future_stock,
+                    held_stock,
+                } => {
+                    if *future_stock <= dec!(0) || *held_stock <= dec!(0) {
+                        continue;
+                    }
+
+                    for lot in holdings.lots.iter_mut().filter(|lot| &lot.symbol == symbol) {
+                        lot.shares = (lot.shares * future_stock) / held_stock;
+                        lot.cost_per_share = (lot.cost_per_share * held_stock) / future_stock;
+                    }
+                }
+                // Spun-off lots keep the acquisition date of their parent lot.
+                TransactionKind::SpinOff {
+                    symbol,
+                    new_symbol,
+                    new_shares_per_share,
+                    cost_allocation_percent,
+                } => {
+                    if *new_shares_per_share == dec!(0) {
+                        continue;
+                    }
+
+                    let allocation = cost_allocation_percent / dec!(100);
+                    let mut new_lots: Vec<Lot> = vec![];
+
+                    for lot in holdings.lots.iter_mut().filter(|lot| &lot.symbol == symbol) {
+                        new_lots.push(Lot {
+                            symbol: new_symbol.to_string(),
+                            date: lot.date,
+                            shares: lot.shares * new_shares_per_share,
+                            cost_per_share: (lot.cost_per_share * allocation)
+                                / new_shares_per_share,
+                        });
+                        lot.cost_per_share *= dec!(1) - allocation;
+                    }
+                    holdings.lots.extend(new_lots);
+                }
+                _ => {}
+            }
+        }
+
+        holdings
+    }
 }
@@ -1,6 +1,9 @@
 #[cfg(test)]
+use crate::stock_market::generate_utc_date_from_date_string;
 use crate::stock_market::stock_portfolio::StockPortfolio;
-use rust_decimal::Decimal;
+use crate::stock_market::transaction_ledger::{
+    Fee, Transaction, TransactionKind, TransactionLedger,
+};
 use rust_decimal_macros::dec;
 
 #[test]
@@ -20,3 +23,127 @@ fn it_gets_capital_losses_and_percent() {
     assert_eq!(capital_gains, dec!(-500));
     assert_eq!(capital_gains_percent, dec!(-50));
 }
+
+fn generate_portfolio() -> StockPortfolio {
+    let mut portfolio = StockPortfolio::new(TransactionLedger::new());
+    portfolio
+        .record(Transaction::new(
+            generate_utc_date_from_date_string("10-01-2022 00:00"),
+            TransactionKind::Deposit {
+                amount: dec!(10000),
+            },
+            vec![],
+        ))
+        .unwrap();
+    portfolio
+        .record(Transaction::new(
+            generate_utc_date_from_date_string("10-03-2022 00:00"),
+            TransactionKind::Buy {
+                symbol: "BNCRP".to_string(),
+                shares: dec!(100),
+                price: dec!(20),
+            },
+            vec![Fee::Fixed(dec!(20))],
+        ))
+        .unwrap();
+    portfolio
+        .record(Transaction::new(
+            generate_utc_date_from_date_string("10-04-2022 00:00"),
+            TransactionKind::Buy {
+                symbol: "BNCRP".to_string(),
+                shares: dec!(100),
+                price: dec!(30),
+            },
+            vec![Fee::Fixed(dec!(20))],
+        ))
+        .unwrap();
+    portfolio
+        .record(Transaction::new(
+            generate_utc_date_from_date_string("10-06-2022 00:00"),
+            TransactionKind::Sell {
+                symbol: "BNCRP".to_string(),
+                shares: dec!(150),
+                price: dec!(40),
+            },
+            vec![Fee::Fixed(dec!(20)), Fee::ExchangeTax(dec!(0.5))],
+        ))
+        .unwrap();
+    portfolio
+}
+
+#[test]
+fn it_gets_net_proceeds_and_net_gains_from_ledger() {
+    let portfolio = generate_portfolio();
+
+    // 6000 - 20 commission - 30 exchange tax
+    assert_eq!(portfolio.get_net_proceeds("BNCRP"), dec!(5950));
+    // FIFO cost: 100 * 20.20 + 50 * 30.20
+    assert_eq!(portfolio.get_net_gains("BNCRP"), dec!(2420));
+    assert_eq!(portfolio.get_shares_held("BNCRP"), dec!(50));
+    assert_eq!(portfolio.get_net_gains("WALA"), dec!(0));
+}
+
+#[test]
+fn it_gets_cash_flow_history() {
+    let portfolio = generate_portfolio();
+    let cash_flow_history = portfolio.get_cash_flow_history();
+
+    assert_eq!(cash_flow_history.len(), 4);
+    assert_eq!(cash_flow_history[1].amount, dec!(-2020));
+    assert_eq!(cash_flow_history[3].balance, dec!(10910));
+    assert_eq!(portfolio.get_cash_balance(), dec!(10910));
+}
+
+#[test]
+fn it_adjusts_open_lots_after_split() {
+    let mut portfolio = generate_portfolio();
+    portfolio
+        .record(
+            Transaction::new_split(
+                generate_utc_date_from_date_string("10-07-2022 00:00"),
+                "BNCRP".to_string(),
+                dec!(2),
+                dec!(1),
+                vec![],
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+    let open_lots = portfolio.get_open_lots("BNCRP");
+
+    assert_eq!(open_lots.len(), 1);
+    assert_eq!(open_lots[0].shares, dec!(100));
+    assert_eq!(open_lots[0].cost_per_share, dec!(15.10));
+}
+
+#[test]
+fn it_rejects_sells_larger_than_the_shares_held() {
+    let mut portfolio = generate_portfolio();
+    let net_gains = portfolio.get_net_gains("BNCRP");
+
+    let oversell = portfolio.record(Transaction::new(
+        generate_utc_date_from_date_string("10-07-2022 00:00"),
+        TransactionKind::Sell {
+            symbol: "BNCRP".to_string(),
+            shares: dec!(60),
+            price: dec!(40),
+        },
+        vec![],
+    ));
+    let backdated_sell = portfolio.record(Transaction::new(
+        generate_utc_date_from_date_string("10-02-2022 00:00"),
+        TransactionKind::Sell {
+            symbol: "BNCRP".to_string(),
+            shares: dec!(10),
+            price: dec!(40),
+        },
+        vec![],
+    ));
+
+    assert!(oversell.is_err());
+    assert!(backdated_sell.is_err());
+    assert_eq!(portfolio.ledger.get_transactions().len(), 4);
+    assert_eq!(portfolio.get_shares_held("BNCRP"), dec!(50));
+    assert_eq!(portfolio.get_net_gains("BNCRP"), net_gains);
+}
@@ -0,0 +1,202 @@
+use std::error::Error;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+// Rates of Percentage and ExchangeTax are expressed in percent (e.g. 0.6 = 0.6%), same as get_interest_income.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Fee {
+    Fixed(Decimal),
+    PerShare(Decimal),
+    Percentage(Decimal),
+    // Exchange / stock transaction taxes are only levied on the selling side.
+    ExchangeTax(Decimal),
+}
+
+impl Fee {
+    pub fn get_amount(&self, shares: Decimal, gross_amount: Decimal, is_sell: bool) -> Decimal {
+        match self {
+            Fee::Fixed(amount) => *amount,
+            Fee::PerShare(amount) => amount * shares,
+            Fee::Percentage(rate) => (rate / dec!(100)) * gross_amount,
+            Fee::ExchangeTax(rate) => {
+                if is_sell {
+                    (rate / dec!(100)) * gross_amount
+                } else {
+                    dec!(0)
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransactionKind {
+    Buy {
+        symbol: String,
+        shares: Decimal,
+        price: Decimal,
+    },
+    Sell {
+        symbol: String,
+        shares: Decimal,
+        price: Decimal,
+    },
+    Dividend {
+        symbol: String,
+        amount: Decimal,
+    },
+    // future_stock for held_stock split (e.g. 5 for 4), same arguments as get_post_split_data.
+    Split {
+        symbol: String,
+        future_stock: Decimal,
+        held_stock: Decimal,
+    },
+    // new_shares_per_share of new_symbol for every share held, cost_allocation_percent of the cost basis moves to them.
+    SpinOff {
+        symbol: String,
+        new_symbol: String,
+        new_shares_per_share: Decimal,
+        cost_allocation_percent: Decimal,
+    },
+    Deposit {
+        amount: Decimal,
+    },
+    Withdrawal {
+        amount: Decimal,
+    },
+    Fee {
+        amount: Decimal,
+    },
+}
+
+#[derive(Clone, Debug)]
+pub struct Transaction {
+    pub date: DateTime<Utc>,
+    pub kind: TransactionKind,
+    pub fees: Vec<Fee>,
+}
+
+impl Transaction {
+    pub fn new(date: DateTime<Utc>, kind: TransactionKind, fees: Vec<Fee>) -> Self {
+        Self { date, kind, fees }
+    }
+
+    // Split transaction with both sides of the ratio checked, a zero side cannot be applied to the holdings.
+    pub fn new_split(
+        date: DateTime<Utc>,
+        symbol: String,
+        future_stock: Decimal,
+        held_stock: Decimal,
+        fees: Vec<Fee>,
+    ) -> Result<Self, Box<dyn Error>> {
+        if future_stock <= dec!(0) || held_stock <= dec!(0) {
+            Err("Split ratio must be positive on both sides")?;
+        }
+
+        Ok(Self::new(
+            date,
+            TransactionKind::Split {
+                symbol,
+                future_stock,
+                held_stock,
+            },
+            fees,
+        ))
+    }
+
+    pub fn get_symbol(&self) -> Option<&str> {
+        match &self.kind {
+            TransactionKind::Buy { symbol, .. }
+            | TransactionKind::Sell { symbol, .. }
+            | TransactionKind::Dividend { symbol, .. }
+            | TransactionKind::Split { symbol, .. }
+            | TransactionKind::SpinOff { symbol, .. } => Some(symbol),
+            _ => None,
+        }
+    }
+
+    pub fn get_gross_amount(&self) -> Decimal {
+        match &self.kind {
+            TransactionKind::Buy { shares, price, .. }
+            | TransactionKind::Sell { shares, price, .. } => shares * price,
+            TransactionKind::Dividend { amount, .. }
+            | TransactionKind::Deposit { amount }
+            | TransactionKind::Withdrawal { amount }
+            | TransactionKind::Fee { amount } => *amount,
+            TransactionKind::Split { .. } | TransactionKind::SpinOff { .. } => dec!(0),
+        }
+    }
+
+    pub fn get_total_fees(&self) -> Decimal {
+        let (shares, is_sell) = match &self.kind {
+            TransactionKind::Buy { shares, .. } => (*shares, false),
+            TransactionKind::Sell { shares, .. } => (*shares, true),
+            _ => (dec!(0), false),
+        };
+        let gross_amount = self.get_gross_amount();
+
+        self.fees.iter().fold(dec!(0), |acc, fee| {
+            acc + fee.get_amount(shares, gross_amount, is_sell)
+        })
+    }
+
+    // Signed movement of cash caused by the transaction, positive when cash comes into the account.
+    pub fn get_cash_flow(&self) -> Decimal {
+        let gross_amount = self.get_gross_amount();
+        let total_fees = self.get_total_fees();
+
+        match &self.kind {
+            TransactionKind::Buy { .. } => -(gross_amount + total_fees),
+            TransactionKind::Sell { .. } => gross_amount - total_fees,
+            TransactionKind::Dividend { .. } | TransactionKind::Deposit { .. } => {
+                gross_amount - total_fees
+            }
+            TransactionKind::Withdrawal { .. } | TransactionKind::Fee { .. } => {
+                -(gross_amount + total_fees)
+            }
+            TransactionKind::Split { .. } | TransactionKind::SpinOff { .. } => -total_fees,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TransactionLedger {
+    transactions: Vec<Transaction>,
+}
+
+impl TransactionLedger {
+    pub fn new() -> Self {
+        Self {
+            transactions: vec![],
+        }
+    }
+
+    // Keeps the ledger ordered by date, transactions on the same date keep their recording order.
+    pub fn record(&mut self, transaction: Transaction) {
+        let idx = self
+            .transactions
+            .iter()
+            .position(|recorded| recorded.date > transaction.date)
+            .unwrap_or(self.transactions.len());
+        self.transactions.insert(idx, transaction);
+    }
+
+    pub fn get_transactions(&self) -> &Vec<Transaction> {
+        &self.transactions
+    }
+
+    pub fn get_transactions_by_symbol(&self, symbol: &str) -> Vec<&Transaction> {
+        self.transactions
+            .iter()
+            .filter(|transaction| transaction.get_symbol() == Some(symbol))
+            .collect()
+    }
+
+    pub fn get_total_fees(&self) -> Decimal {
+        self.transactions.iter().fold(dec!(0), |acc, transaction| {
+            acc + transaction.get_total_fees()
+        })
+    }
+}
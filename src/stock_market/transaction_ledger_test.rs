@@ -0,0 +1,86 @@
+#[cfg(test)]
+use crate::stock_market::generate_utc_date_from_date_string;
+use crate::stock_market::transaction_ledger::*;
+use rust_decimal_macros::dec;
+
+#[test]
+fn it_computes_fees_of_a_buy_transaction() {
+    let transaction = Transaction::new(
+        generate_utc_date_from_date_string("10-03-2022 00:00"),
+        TransactionKind::Buy {
+            symbol: "BNCRP".to_string(),
+            shares: dec!(1000),
+            price: dec!(10),
+        },
+        vec![
+            Fee::Fixed(dec!(20)),
+            Fee::PerShare(dec!(0.01)),
+            Fee::Percentage(dec!(0.25)),
+            Fee::ExchangeTax(dec!(0.6)),
+        ],
+    );
+
+    // Exchange tax is not levied on buys
+    assert_eq!(transaction.get_gross_amount(), dec!(10000));
+    assert_eq!(transaction.get_total_fees(), dec!(55));
+    assert_eq!(transaction.get_cash_flow(), dec!(-10055));
+}
+
+#[test]
+fn it_computes_fees_of_a_sell_transaction() {
+    let transaction = Transaction::new(
+        generate_utc_date_from_date_string("10-03-2022 00:00"),
+        TransactionKind::Sell {
+            symbol: "BNCRP".to_string(),
+            shares: dec!(1000),
+            price: dec!(10),
+        },
+        vec![Fee::Percentage(dec!(0.25)), Fee::ExchangeTax(dec!(0.6))],
+    );
+
+    assert_eq!(transaction.get_total_fees(), dec!(85));
+    assert_eq!(transaction.get_cash_flow(), dec!(9915));
+}
+
+#[test]
+fn it_records_transactions_ordered_by_date() {
+    let mut ledger = TransactionLedger::new();
+    ledger.record(Transaction::new(
+        generate_utc_date_from_date_string("10-05-2022 00:00"),
+        TransactionKind::Withdrawal { amount: dec!(100) },
+        vec![],
+    ));
+    ledger.record(Transaction::new(
+        generate_utc_date_from_date_string("10-01-2022 00:00"),
+        TransactionKind::Deposit { amount: dec!(1000) },
+        vec![],
+    ));
+    ledger.record(Transaction::new(
+        generate_utc_date_from_date_string("10-03-2022 00:00"),
+        TransactionKind::Dividend {
+            symbol: "BNCRP".to_string(),
+            amount: dec!(50),
+        },
+        vec![Fee::Percentage(dec!(10))],
+    ));
+
+    let transactions = ledger.get_transactions();
+
+    assert_eq!(transactions.len(), 3);
+    assert_eq!(
+        transactions[0].kind,
+        TransactionKind::Deposit { amount: dec!(1000) }
+    );
+    assert_eq!(transactions[1].get_cash_flow(), dec!(45));
+    assert_eq!(ledger.get_transactions_by_symbol("BNCRP").len(), 1);
+    assert_eq!(ledger.get_total_fees(), dec!(5));
+}
+
+#[test]
+fn it_rejects_a_split_without_held_stock() {
+    let date = generate_utc_date_from_date_string("10-07-2022 00:00");
+
+    assert!(Transaction::new_split(date, "BNCRP".to_string(), dec!(2), dec!(0), vec![]).is_err());
+    assert!(Transaction::new_split(date, "BNCRP".to_string(), dec!(0), dec!(1), vec![]).is_err());
+    assert!(Transaction::new_split(date, "BNCRP".to_string(), dec!(2), dec!(1), vec![]).is_ok());
+}
@@ -0,0 +1,235 @@
+use std::error::Error;
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use crate::stock_market::{business_organization::Corporation, cap_table::ShareClass};
+
+// Coalitions are enumerated, so the power analysis is limited to this many voters.
+const MAX_VOTERS: usize = 20;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VotingThreshold {
+    // More than half of the votes.
+    SimpleMajority,
+    // At least this percent of the votes, e.g. 66.67 for two thirds.
+    Supermajority(Decimal),
+}
+
+impl VotingThreshold {
+    pub fn is_met(&self, votes: Decimal, total_votes: Decimal) -> bool {
+        if total_votes <= dec!(0) {
+            return false;
+        }
+        match self {
+            VotingThreshold::SimpleMajority => votes * dec!(2) > total_votes,
+            VotingThreshold::Supermajority(percent) => votes * dec!(100) >= total_votes * percent,
+        }
+    }
+}
+
+// Votes per share of each class, classes not listed carry one vote per share.
+#[derive(Clone, Debug, Default)]
+pub struct VotingRights {
+    votes_per_share: Vec<(ShareClass, Decimal)>,
+}
+
+impl VotingRights {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_votes_per_share(&mut self, share_class: ShareClass, votes: Decimal) {
+        match self
+            .votes_per_share
+            .iter()
+            .position(|(existing, _)| existing == &share_class)
+        {
+            Some(idx) => self.votes_per_share[idx].1 = votes,
+            None => self.votes_per_share.push((share_class, votes)),
+        }
+    }
+
+    pub fn get_votes_per_share(&self, share_class: &ShareClass) -> Decimal {
+        self.votes_per_share
+            .iter()
+            .find(|(existing, _)| existing == share_class)
+            .map(|(_, votes)| *votes)
+            .unwrap_or(dec!(1))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Voter {
+    pub name: String,
+    pub votes: Decimal,
+    pub percent: Decimal,
+}
+
+// banzhaf is the voter's share of all swings, shapley_shubik the share of orderings in which the voter is pivotal,
+// both in percent.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PowerIndex {
+    pub name: String,
+    pub votes: Decimal,
+    pub banzhaf: Decimal,
+    pub shapley_shubik: Decimal,
+}
+
+impl Corporation {
+    // Votes from the shares issued in the cap table, unexercised options do not vote.
+    pub fn get_voters(&self, voting_rights: &VotingRights) -> Vec<Voter> {
+        let mut voters: Vec<(String, Decimal)> = vec![];
+        for issuance in self.cap_table.get_issuances().iter() {
+            let votes = Decimal::from(issuance.shares)
+                * voting_rights.get_votes_per_share(&issuance.share_class);
+            match voters.iter().position(|(name, _)| name == &issuance.holder) {
+                Some(idx) => voters[idx].1 += votes,
+                None => voters.push((issuance.holder.clone(), votes)),
+            }
+        }
+
+        let total_votes = voters.iter().fold(dec!(0), |acc, (_, votes)| acc + votes);
+        voters
+            .into_iter()
+            .filter(|(_, votes)| *votes > dec!(0))
+            .map(|(name, votes)| Voter {
+                percent: votes / total_votes * dec!(100),
+                name,
+                votes,
+            })
+            .collect()
+    }
+
+    pub fn has_control(
+        &self,
+        names: &[&str],
+        voting_rights: &VotingRights,
+        threshold: VotingThreshold,
+    ) -> bool {
+        let voters = self.get_voters(voting_rights);
+        let coalition_votes = voters
+            .iter()
+            .filter(|voter| names.contains(&voter.name.as_str()))
+            .fold(dec!(0), |acc, voter| acc + voter.votes);
+
+        threshold.is_met(coalition_votes, get_total_votes(&voters))
+    }
+
+    // The voter that reaches the threshold alone, if any.
+    pub fn get_controlling_holder(
+        &self,
+        voting_rights: &VotingRights,
+        threshold: VotingThreshold,
+    ) -> Option<String> {
+        let voters = self.get_voters(voting_rights);
+        let total_votes = get_total_votes(&voters);
+
+        voters
+            .into_iter()
+            .find(|voter| threshold.is_met(voter.votes, total_votes))
+            .map(|voter| voter.name)
+    }
+
+    // Coalitions that reach the threshold and lose it without any one of their members.
+    pub fn get_minimal_winning_coalitions(
+        &self,
+        voting_rights: &VotingRights,
+        threshold: VotingThreshold,
+    ) -> Result<Vec<Vec<String>>, Box<dyn Error>> {
+        let voters = self.get_voters(voting_rights);
+        let votes = get_coalition_votes(&voters)?;
+        let total_votes = get_total_votes(&voters);
+
+        Ok((0..votes.len())
+            .filter(|coalition| {
+                threshold.is_met(votes[*coalition], total_votes)
+                    && (0..voters.len())
+                        .filter(|idx| coalition & (1 << idx) != 0)
+                        .all(|idx| !threshold.is_met(votes[coalition & !(1 << idx)], total_votes))
+            })
+            .map(|coalition| {
+                voters
+                    .iter()
+                    .enumerate()
+                    .filter(|(idx, _)| coalition & (1 << idx) != 0)
+                    .map(|(_, voter)| voter.name.clone())
+                    .collect()
+            })
+            .collect())
+    }
+
+    pub fn get_power_indices(
+        &self,
+        voting_rights: &VotingRights,
+        threshold: VotingThreshold,
+    ) -> Result<Vec<PowerIndex>, Box<dyn Error>> {
+        let voters = self.get_voters(voting_rights);
+        let votes = get_coalition_votes(&voters)?;
+        let total_votes = get_total_votes(&voters);
+        let voter_count = voters.len();
+
+        // factorials[k] is k!, 20! still fits in u64.
+        let mut factorials: Vec<u64> = vec![1];
+        for k in 1..=voter_count as u64 {
+            factorials.push(factorials[factorials.len() - 1] * k);
+        }
+
+        let mut swings = vec![0u64; voter_count];
+        let mut pivots = vec![dec!(0); voter_count];
+        for coalition in 0..votes.len() {
+            if threshold.is_met(votes[coalition], total_votes) {
+                continue;
+            }
+
+            let size = coalition.count_ones() as usize;
+            for (idx, (swing, pivot)) in swings.iter_mut().zip(pivots.iter_mut()).enumerate() {
+                if coalition & (1 << idx) != 0
+                    || !threshold.is_met(votes[coalition | (1 << idx)], total_votes)
+                {
+                    continue;
+                }
+
+                *swing += 1;
+                *pivot += Decimal::from(factorials[size] * factorials[voter_count - size - 1]);
+            }
+        }
+
+        let total_swings = swings.iter().sum::<u64>();
+        Ok(voters
+            .into_iter()
+            .enumerate()
+            .map(|(idx, voter)| PowerIndex {
+                name: voter.name,
+                votes: voter.votes,
+                banzhaf: if total_swings == 0 {
+                    dec!(0)
+                } else {
+                    Decimal::from(swings[idx]) / Decimal::from(total_swings) * dec!(100)
+                },
+                shapley_shubik: pivots[idx] / Decimal::from(factorials[voter_count]) * dec!(100),
+            })
+            .collect())
+    }
+}
+
+fn get_total_votes(voters: &[Voter]) -> Decimal {
+    voters.iter().fold(dec!(0), |acc, voter| acc + voter.votes)
+}
+
+// Votes of every coalition, indexed by the bitmask of its members.
+fn get_coalition_votes(voters: &[Voter]) -> Result<Vec<Decimal>, Box<dyn Error>> {
+    if voters.len() > MAX_VOTERS {
+        Err(format!(
+            "Power analysis supports at most {} voters",
+            MAX_VOTERS
+        ))?;
+    }
+
+    let mut votes = vec![dec!(0); 1 << voters.len()];
+    for coalition in 1..votes.len() {
+        let idx = coalition.trailing_zeros() as usize;
+        votes[coalition] = votes[coalition & (coalition - 1)] + voters[idx].votes;
+    }
+    Ok(votes)
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+use crate::stock_market::voting_power::*;
+use crate::stock_market::{
+    business_organization::{Corporation, Owner},
+    cap_table::ShareClass,
+};
+use rust_decimal_macros::dec;
+
+// Mark, Ben and Hello hold 50, 49 and 1 common shares.
+fn generate_corporation() -> Corporation {
+    let owners = vec![
+        Owner::new("Mark".to_string(), dec!(50)),
+        Owner::new("Ben".to_string(), dec!(49)),
+        Owner::new("Hello".to_string(), dec!(1)),
+    ];
+    Corporation::new(owners, Some("KamoteCorp".to_string()), Some(100))
+}
+
+#[test]
+fn it_checks_simple_and_supermajority_control() {
+    let corporation = generate_corporation();
+    let voting_rights = VotingRights::new();
+
+    assert!(!corporation.has_control(&["Mark"], &voting_rights, VotingThreshold::SimpleMajority));
+    assert!(corporation.has_control(
+        &["Mark", "Hello"],
+        &voting_rights,
+        VotingThreshold::SimpleMajority
+    ));
+    assert!(!corporation.has_control(
+        &["Mark", "Hello"],
+        &voting_rights,
+        VotingThreshold::Supermajority(dec!(66.67))
+    ));
+    assert_eq!(
+        corporation.get_controlling_holder(&voting_rights, VotingThreshold::SimpleMajority),
+        None
+    );
+}
+
+#[test]
+fn it_finds_minimal_winning_coalitions() {
+    let corporation = generate_corporation();
+    let coalitions = corporation
+        .get_minimal_winning_coalitions(&VotingRights::new(), VotingThreshold::SimpleMajority)
+        .unwrap();
+
+    assert_eq!(
+        coalitions,
+        vec![
+            vec!["Mark".to_string(), "Ben".to_string()],
+            vec!["Mark".to_string(), "Hello".to_string()],
+        ]
+    );
+}
+
+#[test]
+fn it_computes_banzhaf_and_shapley_shubik_power_indices() {
+    let corporation = generate_corporation();
+    let power_indices = corporation
+        .get_power_indices(&VotingRights::new(), VotingThreshold::SimpleMajority)
+        .unwrap();
+
+    // Hello holds 1% of the votes but as much power as Ben.
+    assert_eq!(power_indices[0].banzhaf, dec!(60));
+    assert_eq!(power_indices[1].banzhaf, dec!(20));
+    assert_eq!(power_indices[2].banzhaf, dec!(20));
+    assert_eq!(power_indices[0].shapley_shubik.round_dp(4), dec!(66.6667));
+    assert_eq!(power_indices[2].shapley_shubik.round_dp(4), dec!(16.6667));
+}
+
+#[test]
+fn it_applies_per_class_voting_rights() {
+    let mut corporation = generate_corporation();
+    corporation.cap_table.issue_shares(
+        "Hello",
+        ShareClass::Preferred("Series A".to_string()),
+        10,
+        dec!(10),
+    );
+
+    let mut voting_rights = VotingRights::new();
+    voting_rights.set_votes_per_share(ShareClass::Preferred("Series A".to_string()), dec!(0));
+    assert_eq!(corporation.get_voters(&voting_rights)[2].votes, dec!(1));
+
+    voting_rights.set_votes_per_share(ShareClass::Common, dec!(10));
+    let voters = corporation.get_voters(&voting_rights);
+    assert_eq!(voters[0].percent, dec!(50));
+    assert_eq!(
+        corporation
+            .get_controlling_holder(&voting_rights, VotingThreshold::Supermajority(dec!(50))),
+        Some("Mark".to_string())
+    );
+}
+
+#[test]
+fn it_returns_error_for_too_many_voters() {
+    let owners = (0..21)
+        .map(|idx| Owner::new(format!("Owner {}", idx), dec!(1)))
+        .collect();
+    let corporation = Corporation::new(owners, None, Some(21));
+
+    assert!(corporation
+        .get_power_indices(&VotingRights::new(), VotingThreshold::SimpleMajority)
+        .is_err());
+}
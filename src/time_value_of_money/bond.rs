@@ -0,0 +1,141 @@
+use std::error::Error;
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use crate::time_value_of_money::time_value_of_money::{get_power, TimeValueOfMoney};
+
+// Plain-vanilla fixed coupon bond priced on a coupon date, yields are annual in percent compounded
+// coupons_per_year times (bond-equivalent yield). Prices and risk measures are None when the yield cannot
+// discount the cash flows, see TimeValueOfMoney.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bond {
+    pub face_value: Decimal,
+    // Annual coupon rate in percent, e.g. 6 = 6%.
+    pub coupon_rate: Decimal,
+    pub coupons_per_year: u32,
+    // Coupons left until maturity.
+    pub coupon_periods: u32,
+}
+
+impl Bond {
+    pub fn new(
+        face_value: Decimal,
+        coupon_rate: Decimal,
+        coupons_per_year: u32,
+        coupon_periods: u32,
+    ) -> Result<Self, Box<dyn Error>> {
+        if face_value <= dec!(0) {
+            Err("Face value must be positive")?;
+        }
+        if coupons_per_year == 0 || coupon_periods == 0 {
+            Err("Coupons per year and coupon periods must be positive")?;
+        }
+
+        Ok(Self {
+            face_value,
+            coupon_rate,
+            coupons_per_year,
+            coupon_periods,
+        })
+    }
+
+    pub fn get_coupon_payment(&self) -> Decimal {
+        self.face_value * self.coupon_rate / dec!(100) / Decimal::from(self.coupons_per_year)
+    }
+
+    pub fn get_price(&self, annual_yield: Decimal) -> Option<Decimal> {
+        self.get_discounted_cash_flows(annual_yield)?
+            .iter()
+            .try_fold(dec!(0), |acc, (_, present_value)| {
+                acc.checked_add(*present_value)
+            })
+    }
+
+    pub fn get_yield_to_maturity(&self, price: Decimal) -> Option<Decimal> {
+        let mut cash_flows = vec![-price];
+        cash_flows.extend(self.get_cash_flows());
+
+        TimeValueOfMoney::get_irr(&cash_flows)
+            .map(|periodic_yield| periodic_yield * Decimal::from(self.coupons_per_year))
+    }
+
+    pub fn get_current_yield(&self, price: Decimal) -> Option<Decimal> {
+        if price <= dec!(0) {
+            return None;
+        }
+        Some(self.get_coupon_payment() * Decimal::from(self.coupons_per_year) / price * dec!(100))
+    }
+
+    // Weighted average time in years until the cash flows are received.
+    pub fn get_macaulay_duration(&self, annual_yield: Decimal) -> Option<Decimal> {
+        let weighted_time = self
+            .get_discounted_cash_flows(annual_yield)?
+            .iter()
+            .try_fold(dec!(0), |acc, (period, present_value)| {
+                acc.checked_add(Decimal::from(*period).checked_mul(*present_value)?)
+            })?;
+
+        Some(
+            weighted_time.checked_div(self.get_price(annual_yield)?)?
+                / Decimal::from(self.coupons_per_year),
+        )
+    }
+
+    // Percent change in price for a 1 percentage point change in yield.
+    pub fn get_modified_duration(&self, annual_yield: Decimal) -> Option<Decimal> {
+        self.get_macaulay_duration(annual_yield)?
+            .checked_div(dec!(1) + self.get_periodic_yield(annual_yield))
+    }
+
+    // In years squared.
+    pub fn get_convexity(&self, annual_yield: Decimal) -> Option<Decimal> {
+        let periodic_yield = self.get_periodic_yield(annual_yield);
+        let weighted_time = self
+            .get_discounted_cash_flows(annual_yield)?
+            .iter()
+            .try_fold(dec!(0), |acc, (period, present_value)| {
+                let period = Decimal::from(*period);
+                acc.checked_add((period * (period + dec!(1))).checked_mul(*present_value)?)
+            })?;
+        let coupons_per_year = Decimal::from(self.coupons_per_year);
+
+        Some(
+            weighted_time
+                .checked_div(get_power(dec!(1) + periodic_yield, dec!(2))?)?
+                .checked_div(self.get_price(annual_yield)?)?
+                / (coupons_per_year * coupons_per_year),
+        )
+    }
+
+    fn get_periodic_yield(&self, annual_yield: Decimal) -> Decimal {
+        annual_yield / dec!(100) / Decimal::from(self.coupons_per_year)
+    }
+
+    fn get_cash_flows(&self) -> Vec<Decimal> {
+        (1..=self.coupon_periods)
+            .map(|period| {
+                if period == self.coupon_periods {
+                    self.get_coupon_payment() + self.face_value
+                } else {
+                    self.get_coupon_payment()
+                }
+            })
+            .collect()
+    }
+
+    fn get_discounted_cash_flows(&self, annual_yield: Decimal) -> Option<Vec<(u32, Decimal)>> {
+        let discount_factor = dec!(1) + self.get_periodic_yield(annual_yield);
+
+        self.get_cash_flows()
+            .into_iter()
+            .zip(1..)
+            .map(|(cash_flow, period)| {
+                Some((
+                    period,
+                    cash_flow.checked_div(get_power(discount_factor, Decimal::from(period))?)?,
+                ))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+use crate::time_value_of_money::bond::*;
+use rust_decimal_macros::dec;
+
+#[test]
+fn it_prices_bond() {
+    let bond = Bond::new(dec!(1000), dec!(6), 2, 10).unwrap();
+
+    assert_eq!(bond.get_coupon_payment(), dec!(30));
+    assert_eq!(bond.get_price(dec!(6)).unwrap().round_dp(2), dec!(1000));
+    assert_eq!(bond.get_price(dec!(8)).unwrap().round_dp(2), dec!(918.89));
+    assert_eq!(bond.get_price(dec!(-200)), None);
+}
+
+#[test]
+fn it_gets_yield_to_maturity_and_current_yield() {
+    let bond = Bond::new(dec!(1000), dec!(6), 2, 10).unwrap();
+
+    assert_eq!(
+        bond.get_yield_to_maturity(dec!(918.89))
+            .unwrap()
+            .round_dp(2),
+        dec!(8)
+    );
+    assert_eq!(
+        bond.get_current_yield(dec!(950)).unwrap().round_dp(2),
+        dec!(6.32)
+    );
+    assert_eq!(bond.get_current_yield(dec!(0)), None);
+}
+
+#[test]
+fn it_gets_duration_and_convexity() {
+    let bond = Bond::new(dec!(1000), dec!(6), 2, 10).unwrap();
+
+    assert_eq!(
+        bond.get_macaulay_duration(dec!(6)).unwrap().round_dp(4),
+        dec!(4.3931)
+    );
+    assert_eq!(
+        bond.get_modified_duration(dec!(6)).unwrap().round_dp(4),
+        dec!(4.2651)
+    );
+    assert_eq!(
+        bond.get_convexity(dec!(6)).unwrap().round_dp(4),
+        dec!(21.7665)
+    );
+}
+
+#[test]
+fn it_rejects_invalid_bonds() {
+    assert!(Bond::new(dec!(0), dec!(6), 2, 10).is_err());
+    assert!(Bond::new(dec!(1000), dec!(6), 2, 0).is_err());
+}
@@ -0,0 +1,61 @@
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DayCountConvention {
+    Actual360,
+    Actual365,
+    // US (bond basis): a 31st is treated as the 30th, the end date only when the start date is the 30th or 31st.
+    Thirty360,
+    // ISDA: days falling in leap years count over 366, the others over 365.
+    ActualActual,
+}
+
+impl DayCountConvention {
+    pub fn get_day_count(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> i64 {
+        let (start, end) = (start.date_naive(), end.date_naive());
+
+        match self {
+            DayCountConvention::Thirty360 => {
+                let start_day = start.day().min(30) as i64;
+                let end_day = if start_day == 30 {
+                    end.day().min(30) as i64
+                } else {
+                    end.day() as i64
+                };
+
+                360 * (end.year() - start.year()) as i64
+                    + 30 * (end.month() as i64 - start.month() as i64)
+                    + (end_day - start_day)
+            }
+            _ => (end - start).num_days(),
+        }
+    }
+
+    pub fn get_year_fraction(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Decimal {
+        match self {
+            DayCountConvention::Actual360 | DayCountConvention::Thirty360 => {
+                Decimal::from(self.get_day_count(start, end)) / dec!(360)
+            }
+            DayCountConvention::Actual365 => {
+                Decimal::from(self.get_day_count(start, end)) / dec!(365)
+            }
+            DayCountConvention::ActualActual => {
+                if end < start {
+                    return -self.get_year_fraction(end, start);
+                }
+
+                let (start, end) = (start.date_naive(), end.date_naive());
+                (start.year()..=end.year()).fold(dec!(0), |acc, year| {
+                    let year_start = NaiveDate::from_ymd_opt(year, 1, 1).unwrap();
+                    let next_year_start = NaiveDate::from_ymd_opt(year + 1, 1, 1).unwrap();
+                    let days_in_year = (next_year_start - year_start).num_days();
+                    let days = (end.min(next_year_start) - start.max(year_start)).num_days();
+
+                    acc + Decimal::from(days) / Decimal::from(days_in_year)
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(test)]
+use crate::time_value_of_money::day_count::*;
+use crate::time_value_of_money::generate_utc_date_from_date_string;
+use rust_decimal_macros::dec;
+
+#[test]
+fn it_gets_year_fraction_by_convention() {
+    let start = generate_utc_date_from_date_string("01-15-2022 00:00");
+    let end = generate_utc_date_from_date_string("07-15-2022 00:00");
+
+    assert_eq!(DayCountConvention::Actual360.get_day_count(start, end), 181);
+    assert_eq!(
+        DayCountConvention::Actual360
+            .get_year_fraction(start, end)
+            .round_dp(6),
+        dec!(0.502778)
+    );
+    assert_eq!(
+        DayCountConvention::Actual365
+            .get_year_fraction(start, end)
+            .round_dp(6),
+        dec!(0.495890)
+    );
+    assert_eq!(
+        DayCountConvention::Thirty360.get_year_fraction(start, end),
+        dec!(0.5)
+    );
+}
+
+#[test]
+fn it_gets_thirty_360_day_count_on_month_ends() {
+    let convention = DayCountConvention::Thirty360;
+
+    assert_eq!(
+        convention.get_day_count(
+            generate_utc_date_from_date_string("01-31-2022 00:00"),
+            generate_utc_date_from_date_string("03-31-2022 00:00")
+        ),
+        60
+    );
+    assert_eq!(
+        convention.get_day_count(
+            generate_utc_date_from_date_string("02-28-2022 00:00"),
+            generate_utc_date_from_date_string("03-31-2022 00:00")
+        ),
+        33
+    );
+}
+
+#[test]
+fn it_gets_actual_actual_year_fraction_across_leap_year() {
+    let start = generate_utc_date_from_date_string("07-01-2023 00:00");
+    let end = generate_utc_date_from_date_string("07-01-2024 00:00");
+
+    assert_eq!(
+        DayCountConvention::ActualActual
+            .get_year_fraction(start, end)
+            .round_dp(6),
+        dec!(1.001377)
+    );
+    assert_eq!(
+        DayCountConvention::ActualActual
+            .get_year_fraction(end, start)
+            .round_dp(6),
+        dec!(-1.001377)
+    );
+}
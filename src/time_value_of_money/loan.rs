@@ -0,0 +1,133 @@
+use std::error::Error;
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use crate::time_value_of_money::time_value_of_money::{
+    get_level_payment_schedule, AmortizationPayment, AnnuityTiming, TimeValueOfMoney,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LoanType {
+    // Level payments that fully repay the principal.
+    Fixed,
+    // Only interest is paid, the principal is repaid with the last payment.
+    InterestOnly,
+    // Level payments as if the loan ran for amortization_periods, the remaining balance is due with the last payment.
+    Balloon { amortization_periods: u32 },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Loan {
+    pub principal: Decimal,
+    // Annual rate in percent, e.g. 12 = 12%.
+    pub annual_rate: Decimal,
+    pub payments_per_year: u32,
+    pub periods: u32,
+    pub loan_type: LoanType,
+}
+
+impl Loan {
+    pub fn new(
+        principal: Decimal,
+        annual_rate: Decimal,
+        payments_per_year: u32,
+        periods: u32,
+        loan_type: LoanType,
+    ) -> Result<Self, Box<dyn Error>> {
+        if principal <= dec!(0) {
+            Err("Principal must be positive")?;
+        }
+        if payments_per_year == 0 || periods == 0 {
+            Err("Payments per year and periods must be positive")?;
+        }
+        if let LoanType::Balloon {
+            amortization_periods,
+        } = loan_type
+        {
+            if amortization_periods < periods {
+                Err("Balloon amortization periods cannot be shorter than the loan")?;
+            }
+        }
+
+        let loan = Self {
+            principal,
+            annual_rate,
+            payments_per_year,
+            periods,
+            loan_type,
+        };
+        if let Some(amortization_periods) = loan.get_amortization_periods() {
+            if TimeValueOfMoney::get_annuity_payment(
+                principal,
+                loan.get_periodic_rate(),
+                amortization_periods,
+                AnnuityTiming::Ordinary,
+            )
+            .is_none()
+            {
+                Err("Payment cannot be computed at this rate and number of periods")?;
+            }
+        }
+
+        Ok(loan)
+    }
+
+    pub fn get_periodic_rate(&self) -> Decimal {
+        self.annual_rate / Decimal::from(self.payments_per_year)
+    }
+
+    // Regular payment rounded to cents, the last payment can differ.
+    pub fn get_payment(&self) -> Decimal {
+        let amortization_periods = match self.get_amortization_periods() {
+            Some(amortization_periods) => amortization_periods,
+            None => return (self.principal * self.get_periodic_rate() / dec!(100)).round_dp(2),
+        };
+
+        TimeValueOfMoney::get_annuity_payment(
+            self.principal,
+            self.get_periodic_rate(),
+            amortization_periods,
+            AnnuityTiming::Ordinary,
+        )
+        .unwrap_or_default()
+        .round_dp(2)
+    }
+
+    pub fn get_amortization_schedule(&self) -> Vec<AmortizationPayment> {
+        get_level_payment_schedule(
+            self.principal,
+            self.get_periodic_rate(),
+            self.periods,
+            self.get_payment(),
+        )
+    }
+
+    pub fn get_total_interest(&self) -> Decimal {
+        self.get_amortization_schedule()
+            .iter()
+            .fold(dec!(0), |acc, payment| acc + payment.interest)
+    }
+
+    // Payments made within the given year of the loan (starting at 1), usable as a fixed cost in BusinessModelling.
+    pub fn get_annual_debt_service(&self, year: u32) -> Decimal {
+        let first_period = (year.max(1) - 1) * self.payments_per_year + 1;
+        let last_period = year * self.payments_per_year;
+
+        self.get_amortization_schedule()
+            .iter()
+            .filter(|payment| payment.period >= first_period && payment.period <= last_period)
+            .fold(dec!(0), |acc, payment| acc + payment.payment)
+    }
+
+    // Periods the level payment is computed over, None when only interest is paid.
+    fn get_amortization_periods(&self) -> Option<u32> {
+        match self.loan_type {
+            LoanType::Fixed => Some(self.periods),
+            LoanType::Balloon {
+                amortization_periods,
+            } => Some(amortization_periods),
+            LoanType::InterestOnly => None,
+        }
+    }
+}
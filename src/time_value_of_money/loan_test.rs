@@ -0,0 +1,77 @@
+#[cfg(test)]
+use crate::time_value_of_money::loan::*;
+use crate::time_value_of_money::time_value_of_money::AmortizationPayment;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn get_payments(schedule: &[AmortizationPayment]) -> Vec<Decimal> {
+    schedule.iter().map(|payment| payment.payment).collect()
+}
+
+#[test]
+fn it_amortizes_fixed_loan() {
+    let loan = Loan::new(dec!(1000), dec!(12), 12, 3, LoanType::Fixed).unwrap();
+    let schedule = loan.get_amortization_schedule();
+
+    assert_eq!(loan.get_periodic_rate(), dec!(1));
+    assert_eq!(loan.get_payment(), dec!(340.02));
+    assert_eq!(
+        get_payments(&schedule),
+        vec![dec!(340.02), dec!(340.02), dec!(340.03)]
+    );
+    assert_eq!(schedule[2].balance, dec!(0));
+    assert_eq!(loan.get_total_interest(), dec!(20.07));
+}
+
+#[test]
+fn it_amortizes_interest_only_loan() {
+    let loan = Loan::new(dec!(1000), dec!(12), 12, 24, LoanType::InterestOnly).unwrap();
+    let schedule = loan.get_amortization_schedule();
+
+    assert_eq!(loan.get_payment(), dec!(10));
+    assert_eq!(schedule[0].principal, dec!(0));
+    assert_eq!(schedule[23].payment, dec!(1010));
+    assert_eq!(schedule[23].balance, dec!(0));
+    assert_eq!(loan.get_total_interest(), dec!(240));
+    assert_eq!(loan.get_annual_debt_service(1), dec!(120));
+    assert_eq!(loan.get_annual_debt_service(2), dec!(1120));
+}
+
+#[test]
+fn it_amortizes_balloon_loan() {
+    let loan = Loan::new(
+        dec!(1000),
+        dec!(12),
+        12,
+        3,
+        LoanType::Balloon {
+            amortization_periods: 12,
+        },
+    )
+    .unwrap();
+    let schedule = loan.get_amortization_schedule();
+
+    assert_eq!(
+        get_payments(&schedule),
+        vec![dec!(88.85), dec!(88.85), dec!(849.93)]
+    );
+    assert_eq!(schedule[1].balance, dec!(841.51));
+    assert_eq!(schedule[2].balance, dec!(0));
+}
+
+#[test]
+fn it_rejects_invalid_loans() {
+    assert!(Loan::new(dec!(0), dec!(12), 12, 3, LoanType::Fixed).is_err());
+    assert!(Loan::new(dec!(1000), dec!(12), 0, 3, LoanType::Fixed).is_err());
+    assert!(Loan::new(
+        dec!(1000),
+        dec!(12),
+        12,
+        3,
+        LoanType::Balloon {
+            amortization_periods: 2
+        }
+    )
+    .is_err());
+    assert!(Loan::new(dec!(1000), dec!(-1200), 12, 3, LoanType::Fixed).is_err());
+}
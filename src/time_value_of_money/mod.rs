@@ -0,0 +1,24 @@
+pub mod bond;
+#[cfg(test)]
+mod bond_test;
+pub mod day_count;
+#[cfg(test)]
+mod day_count_test;
+pub mod loan;
+#[cfg(test)]
+mod loan_test;
+pub mod schedule;
+#[cfg(test)]
+mod schedule_test;
+pub mod time_value_of_money;
+#[cfg(test)]
+mod time_value_of_money_test;
+
+// Test dates are written as "%m-%d-%Y %H:%M" and read as UTC.
+#[cfg(test)]
+pub(crate) fn generate_utc_date_from_date_string(
+    date_string: &str,
+) -> chrono::DateTime<chrono::Utc> {
+    let day_one = chrono::NaiveDateTime::parse_from_str(date_string, "%m-%d-%Y %H:%M").unwrap();
+    chrono::TimeZone::from_utc_datetime(&chrono::Utc, &day_one)
+}
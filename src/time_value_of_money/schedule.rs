@@ -0,0 +1,126 @@
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, TimeZone, Utc, Weekday};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Frequency {
+    Monthly,
+    Quarterly,
+    Semiannual,
+    Annual,
+}
+
+impl Frequency {
+    pub fn get_months(&self) -> u32 {
+        match self {
+            Frequency::Monthly => 1,
+            Frequency::Quarterly => 3,
+            Frequency::Semiannual => 6,
+            Frequency::Annual => 12,
+        }
+    }
+
+    pub fn get_periods_per_year(&self) -> u32 {
+        12 / self.get_months()
+    }
+}
+
+// How a payment date falling on a weekend or holiday is moved.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BusinessDayConvention {
+    Unadjusted,
+    Following,
+    // Following, unless that crosses into the next month, then preceding.
+    ModifiedFollowing,
+    Preceding,
+}
+
+impl BusinessDayConvention {
+    pub fn adjust(&self, date: DateTime<Utc>, holidays: &[NaiveDate]) -> DateTime<Utc> {
+        let naive_date = date.date_naive();
+        let adjusted_date = match self {
+            BusinessDayConvention::Unadjusted => naive_date,
+            BusinessDayConvention::Following => get_business_day(naive_date, 1, holidays),
+            BusinessDayConvention::Preceding => get_business_day(naive_date, -1, holidays),
+            BusinessDayConvention::ModifiedFollowing => {
+                let following = get_business_day(naive_date, 1, holidays);
+                if following.month() == naive_date.month() {
+                    following
+                } else {
+                    get_business_day(naive_date, -1, holidays)
+                }
+            }
+        };
+
+        Utc.from_utc_datetime(&adjusted_date.and_time(date.time()))
+    }
+}
+
+// start and end are the unadjusted accrual dates, payment_date is end moved to a business day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchedulePeriod {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub payment_date: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+pub struct Schedule {}
+
+impl Schedule {
+    // Periods rolled forward from start every frequency months, a final short period ends on end.
+    // Dates on the 29th to the 31st roll to the last day of shorter months.
+    pub fn get_periods(
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        frequency: Frequency,
+        business_day_convention: BusinessDayConvention,
+        holidays: &[NaiveDate],
+    ) -> Vec<SchedulePeriod> {
+        let mut periods: Vec<SchedulePeriod> = vec![];
+        let mut period_start = start;
+
+        for idx in 1.. {
+            if period_start >= end {
+                break;
+            }
+
+            let period_end = start
+                .checked_add_months(Months::new(frequency.get_months() * idx))
+                .map(|period_end| period_end.min(end))
+                .unwrap_or(end);
+
+            periods.push(SchedulePeriod {
+                start: period_start,
+                end: period_end,
+                payment_date: business_day_convention.adjust(period_end, holidays),
+            });
+            period_start = period_end;
+        }
+
+        periods
+    }
+
+    pub fn get_payment_dates(
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        frequency: Frequency,
+        business_day_convention: BusinessDayConvention,
+        holidays: &[NaiveDate],
+    ) -> Vec<DateTime<Utc>> {
+        Schedule::get_periods(start, end, frequency, business_day_convention, holidays)
+            .into_iter()
+            .map(|period| period.payment_date)
+            .collect()
+    }
+
+    pub fn is_business_day(date: NaiveDate, holidays: &[NaiveDate]) -> bool {
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !holidays.contains(&date)
+    }
+}
+
+fn get_business_day(date: NaiveDate, step: i64, holidays: &[NaiveDate]) -> NaiveDate {
+    let mut date = date;
+    while !Schedule::is_business_day(date, holidays) {
+        date += Duration::days(step);
+    }
+    date
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+use crate::time_value_of_money::generate_utc_date_from_date_string;
+use crate::time_value_of_money::schedule::*;
+use chrono::NaiveDate;
+
+#[test]
+fn it_rolls_monthly_schedule_on_month_ends() {
+    let start = generate_utc_date_from_date_string("01-31-2022 00:00");
+    let end = generate_utc_date_from_date_string("05-31-2022 00:00");
+
+    let following = Schedule::get_payment_dates(
+        start,
+        end,
+        Frequency::Monthly,
+        BusinessDayConvention::Following,
+        &[],
+    );
+    let modified_following = Schedule::get_payment_dates(
+        start,
+        end,
+        Frequency::Monthly,
+        BusinessDayConvention::ModifiedFollowing,
+        &[],
+    );
+
+    assert_eq!(
+        following,
+        vec![
+            generate_utc_date_from_date_string("02-28-2022 00:00"),
+            generate_utc_date_from_date_string("03-31-2022 00:00"),
+            generate_utc_date_from_date_string("05-02-2022 00:00"),
+            generate_utc_date_from_date_string("05-31-2022 00:00"),
+        ]
+    );
+    assert_eq!(
+        modified_following[2],
+        generate_utc_date_from_date_string("04-29-2022 00:00")
+    );
+}
+
+#[test]
+fn it_ends_quarterly_schedule_with_short_period() {
+    let periods = Schedule::get_periods(
+        generate_utc_date_from_date_string("01-15-2022 00:00"),
+        generate_utc_date_from_date_string("08-01-2022 00:00"),
+        Frequency::Quarterly,
+        BusinessDayConvention::Following,
+        &[NaiveDate::from_ymd_opt(2022, 4, 15).unwrap()],
+    );
+
+    assert_eq!(periods.len(), 3);
+    assert_eq!(
+        periods[0].end,
+        generate_utc_date_from_date_string("04-15-2022 00:00")
+    );
+    assert_eq!(
+        periods[0].payment_date,
+        generate_utc_date_from_date_string("04-18-2022 00:00")
+    );
+    assert_eq!(periods[1].start, periods[0].end);
+    assert_eq!(
+        periods[2].start,
+        generate_utc_date_from_date_string("07-15-2022 00:00")
+    );
+    assert_eq!(
+        periods[2].end,
+        generate_utc_date_from_date_string("08-01-2022 00:00")
+    );
+}
+
+#[test]
+fn it_gets_periods_per_year() {
+    assert_eq!(Frequency::Monthly.get_periods_per_year(), 12);
+    assert_eq!(Frequency::Quarterly.get_periods_per_year(), 4);
+    assert_eq!(Frequency::Semiannual.get_periods_per_year(), 2);
+    assert_eq!(Frequency::Annual.get_periods_per_year(), 1);
+}
@@ -0,0 +1,324 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+use rust_decimal_macros::dec;
+
+// Rates are expressed in percent per period (e.g. 5 = 5%), same as get_interest_income.
+// Values that go through a power of (1 + rate) are None when the rate is -100% or the power overflows.
+// Compounding is None as well when it happens zero times a year.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AnnuityTiming {
+    // Payments at the end of each period.
+    Ordinary,
+    // Payments at the start of each period.
+    Due,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AmortizationPayment {
+    pub period: u32,
+    pub payment: Decimal,
+    pub interest: Decimal,
+    pub principal: Decimal,
+    pub balance: Decimal,
+}
+
+#[derive(Debug)]
+pub struct TimeValueOfMoney {}
+
+impl TimeValueOfMoney {
+    pub fn get_simple_interest(rate: Decimal, principal: Decimal, periods: Decimal) -> Decimal {
+        principal * (rate / dec!(100)) * periods
+    }
+
+    // Principal grown at an annual rate compounded compounds_per_year times (e.g. 12 for monthly).
+    pub fn get_compound_amount(
+        principal: Decimal,
+        annual_rate: Decimal,
+        compounds_per_year: u32,
+        years: Decimal,
+    ) -> Option<Decimal> {
+        let compounds_per_year = Decimal::from(compounds_per_year);
+        let periodic_rate = (annual_rate / dec!(100)).checked_div(compounds_per_year)?;
+        principal.checked_mul(get_power(
+            dec!(1) + periodic_rate,
+            compounds_per_year.checked_mul(years)?,
+        )?)
+    }
+
+    pub fn get_continuous_compound_amount(
+        principal: Decimal,
+        annual_rate: Decimal,
+        years: Decimal,
+    ) -> Option<Decimal> {
+        let exponent = (annual_rate / dec!(100)).checked_mul(years)?.to_f64()?;
+        principal.checked_mul(Decimal::from_f64(exponent.exp())?)
+    }
+
+    pub fn get_effective_annual_rate(
+        nominal_rate: Decimal,
+        compounds_per_year: u32,
+    ) -> Option<Decimal> {
+        let compound_amount = TimeValueOfMoney::get_compound_amount(
+            dec!(1),
+            nominal_rate,
+            compounds_per_year,
+            dec!(1),
+        )?;
+        (compound_amount - dec!(1)).checked_mul(dec!(100))
+    }
+
+    pub fn get_future_value(
+        present_value: Decimal,
+        rate: Decimal,
+        periods: Decimal,
+    ) -> Option<Decimal> {
+        present_value.checked_mul(get_power(dec!(1) + rate / dec!(100), periods)?)
+    }
+
+    pub fn get_present_value(
+        future_value: Decimal,
+        rate: Decimal,
+        periods: Decimal,
+    ) -> Option<Decimal> {
+        future_value.checked_div(get_power(dec!(1) + rate / dec!(100), periods)?)
+    }
+
+    pub fn get_annuity_present_value(
+        payment: Decimal,
+        rate: Decimal,
+        periods: u32,
+        timing: AnnuityTiming,
+    ) -> Option<Decimal> {
+        let rate = rate / dec!(100);
+        if rate == dec!(0) {
+            return payment.checked_mul(Decimal::from(periods));
+        }
+
+        let discount = dec!(1).checked_div(get_power(dec!(1) + rate, Decimal::from(periods))?)?;
+        let present_value = payment.checked_mul(dec!(1) - discount)?.checked_div(rate)?;
+        match timing {
+            AnnuityTiming::Ordinary => Some(present_value),
+            AnnuityTiming::Due => present_value.checked_mul(dec!(1) + rate),
+        }
+    }
+
+    pub fn get_annuity_future_value(
+        payment: Decimal,
+        rate: Decimal,
+        periods: u32,
+        timing: AnnuityTiming,
+    ) -> Option<Decimal> {
+        let rate = rate / dec!(100);
+        if rate == dec!(0) {
+            return payment.checked_mul(Decimal::from(periods));
+        }
+
+        let future_value = payment
+            .checked_mul(get_power(dec!(1) + rate, Decimal::from(periods))? - dec!(1))?
+            .checked_div(rate)?;
+        match timing {
+            AnnuityTiming::Ordinary => Some(future_value),
+            AnnuityTiming::Due => future_value.checked_mul(dec!(1) + rate),
+        }
+    }
+
+    // Level payment that pays off present_value over the given periods.
+    pub fn get_annuity_payment(
+        present_value: Decimal,
+        rate: Decimal,
+        periods: u32,
+        timing: AnnuityTiming,
+    ) -> Option<Decimal> {
+        if periods == 0 {
+            return None;
+        }
+
+        let annuity_factor =
+            TimeValueOfMoney::get_annuity_present_value(dec!(1), rate, periods, timing)?;
+        present_value.checked_div(annuity_factor)
+    }
+
+    pub fn get_perpetuity_present_value(payment: Decimal, rate: Decimal) -> Option<Decimal> {
+        if rate <= dec!(0) {
+            return None;
+        }
+        Some(payment / (rate / dec!(100)))
+    }
+
+    // payment is the first payment, received one period from now and growing by growth_rate afterwards.
+    pub fn get_growing_perpetuity_present_value(
+        payment: Decimal,
+        rate: Decimal,
+        growth_rate: Decimal,
+    ) -> Option<Decimal> {
+        if rate <= growth_rate {
+            return None;
+        }
+        Some(payment / ((rate - growth_rate) / dec!(100)))
+    }
+
+    // cash_flows[0] happens now and is not discounted, cash_flows[i] happens i periods from now.
+    pub fn get_npv(rate: Decimal, cash_flows: &[Decimal]) -> Option<Decimal> {
+        let discount_factor = dec!(1) + rate / dec!(100);
+        let mut discount = dec!(1);
+
+        cash_flows.iter().try_fold(dec!(0), |acc, cash_flow| {
+            let present_value = cash_flow.checked_div(discount)?;
+            discount = discount.checked_mul(discount_factor)?;
+            acc.checked_add(present_value)
+        })
+    }
+
+    // Rate per period where the npv of the cash flows is zero.
+    pub fn get_irr(cash_flows: &[Decimal]) -> Option<Decimal> {
+        let cash_flows = cash_flows
+            .iter()
+            .enumerate()
+            .map(|(period, amount)| (period as f64, amount.to_f64().unwrap()))
+            .collect::<Vec<(f64, f64)>>();
+
+        get_rate_by_bisection(&cash_flows)
+    }
+
+    // Dated cash flows discounted to the first date with an annual rate and actual/365 years.
+    pub fn get_xnpv(rate: Decimal, cash_flows: &[(DateTime<Utc>, Decimal)]) -> Option<Decimal> {
+        let first_date = cash_flows.first()?.0;
+        let rate = (rate / dec!(100)).to_f64().unwrap();
+
+        let xnpv = get_dated_cash_flows(cash_flows, first_date)
+            .iter()
+            .fold(0.0, |acc, (years, amount)| {
+                acc + amount / (1.0 + rate).powf(*years)
+            });
+        Decimal::from_f64(xnpv)
+    }
+
+    // Annual rate where the xnpv of the dated cash flows is zero.
+    pub fn get_xirr(cash_flows: &[(DateTime<Utc>, Decimal)]) -> Option<Decimal> {
+        let first_date = cash_flows.first()?.0;
+        get_rate_by_bisection(&get_dated_cash_flows(cash_flows, first_date))
+    }
+
+    // Level payments rounded to cents, the last payment absorbs the rounding so the balance ends at zero.
+    pub fn get_amortization_schedule(
+        principal: Decimal,
+        rate: Decimal,
+        periods: u32,
+    ) -> Option<Vec<AmortizationPayment>> {
+        let payment = TimeValueOfMoney::get_annuity_payment(
+            principal,
+            rate,
+            periods,
+            AnnuityTiming::Ordinary,
+        )?
+        .round_dp(2);
+
+        Some(get_level_payment_schedule(
+            principal, rate, periods, payment,
+        ))
+    }
+}
+
+// Interest is rounded to cents, the principal paid is whatever payment is left after interest and the last period
+// pays off the remaining balance.
+pub(crate) fn get_level_payment_schedule(
+    principal: Decimal,
+    rate: Decimal,
+    periods: u32,
+    payment: Decimal,
+) -> Vec<AmortizationPayment> {
+    let mut balance = principal;
+
+    (1..=periods)
+        .map(|period| {
+            let interest = (balance * rate / dec!(100)).round_dp(2);
+            let principal_paid = if period == periods {
+                balance
+            } else {
+                (payment - interest).max(dec!(0)).min(balance)
+            };
+            balance -= principal_paid;
+
+            AmortizationPayment {
+                period,
+                payment: interest + principal_paid,
+                interest,
+                principal: principal_paid,
+                balance,
+            }
+        })
+        .collect()
+}
+
+// Integer exponents are multiplied out in Decimal, fractional ones go through f64.
+// None when the power does not fit a Decimal or a negative exponent divides by zero.
+pub(crate) fn get_power(base: Decimal, exponent: Decimal) -> Option<Decimal> {
+    if exponent.fract() != dec!(0) {
+        return Decimal::from_f64(base.to_f64()?.powf(exponent.to_f64()?));
+    }
+
+    let mut remaining = exponent.abs().to_u64()?;
+    let mut squared = base;
+    let mut power = dec!(1);
+
+    while remaining > 0 {
+        if remaining % 2 == 1 {
+            power = power.checked_mul(squared)?;
+        }
+        remaining /= 2;
+        if remaining > 0 {
+            squared = squared.checked_mul(squared)?;
+        }
+    }
+
+    if exponent < dec!(0) {
+        dec!(1).checked_div(power)
+    } else {
+        Some(power)
+    }
+}
+
+fn get_dated_cash_flows(
+    cash_flows: &[(DateTime<Utc>, Decimal)],
+    first_date: DateTime<Utc>,
+) -> Vec<(f64, f64)> {
+    cash_flows
+        .iter()
+        .map(|(date, amount)| {
+            (
+                (*date - first_date).num_days() as f64 / 365.0,
+                amount.to_f64().unwrap(),
+            )
+        })
+        .collect()
+}
+
+// Rate in percent where Σ cash_flow / (1 + r)^periods = 0, solved through bisection.
+// Cash flows without a sign change have no rate.
+fn get_rate_by_bisection(cash_flows: &[(f64, f64)]) -> Option<Decimal> {
+    let npv = |rate: f64| {
+        cash_flows.iter().fold(0.0, |acc, (periods, amount)| {
+            acc + amount / (1.0 + rate).powf(*periods)
+        })
+    };
+
+    let (mut low, mut high) = (-0.9999, 100.0);
+    if npv(low).signum() == npv(high).signum() {
+        return None;
+    }
+
+    for _ in 0..200 {
+        let mid = (low + high) / 2.0;
+        if npv(mid).signum() == npv(low).signum() {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    Decimal::from_f64((low + high) / 2.0 * 100.0)
+}
@@ -0,0 +1,195 @@
+#[cfg(test)]
+use crate::time_value_of_money::generate_utc_date_from_date_string;
+use crate::time_value_of_money::time_value_of_money::*;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+#[test]
+fn it_gets_simple_and_compound_interest() {
+    let simple_interest = TimeValueOfMoney::get_simple_interest(dec!(5), dec!(1000), dec!(2));
+    let compound_amount =
+        TimeValueOfMoney::get_compound_amount(dec!(1000), dec!(12), 12, dec!(1)).unwrap();
+    let continuous_compound_amount =
+        TimeValueOfMoney::get_continuous_compound_amount(dec!(1000), dec!(5), dec!(1)).unwrap();
+    let effective_annual_rate = TimeValueOfMoney::get_effective_annual_rate(dec!(12), 12).unwrap();
+
+    assert_eq!(simple_interest, dec!(100));
+    assert_eq!(compound_amount.round_dp(2), dec!(1126.83));
+    assert_eq!(continuous_compound_amount.round_dp(2), dec!(1051.27));
+    assert_eq!(effective_annual_rate.round_dp(2), dec!(12.68));
+}
+
+#[test]
+fn it_gets_present_and_future_value() {
+    let future_value = TimeValueOfMoney::get_future_value(dec!(1000), dec!(10), dec!(2)).unwrap();
+    let present_value = TimeValueOfMoney::get_present_value(dec!(1210), dec!(10), dec!(2)).unwrap();
+    let half_period_future_value =
+        TimeValueOfMoney::get_future_value(dec!(1000), dec!(21), dec!(0.5)).unwrap();
+
+    assert_eq!(future_value, dec!(1210));
+    assert_eq!(present_value, dec!(1000));
+    assert_eq!(half_period_future_value.round_dp(2), dec!(1100));
+}
+
+#[test]
+fn it_gets_annuity_values_and_payment() {
+    let ordinary_present_value = TimeValueOfMoney::get_annuity_present_value(
+        dec!(100),
+        dec!(10),
+        3,
+        AnnuityTiming::Ordinary,
+    )
+    .unwrap();
+    let due_present_value =
+        TimeValueOfMoney::get_annuity_present_value(dec!(100), dec!(10), 3, AnnuityTiming::Due)
+            .unwrap();
+    let ordinary_future_value =
+        TimeValueOfMoney::get_annuity_future_value(dec!(100), dec!(10), 3, AnnuityTiming::Ordinary)
+            .unwrap();
+    let due_future_value =
+        TimeValueOfMoney::get_annuity_future_value(dec!(100), dec!(10), 3, AnnuityTiming::Due)
+            .unwrap();
+    let payment =
+        TimeValueOfMoney::get_annuity_payment(dec!(1000), dec!(10), 3, AnnuityTiming::Ordinary)
+            .unwrap();
+
+    assert_eq!(ordinary_present_value.round_dp(2), dec!(248.69));
+    assert_eq!(due_present_value.round_dp(2), dec!(273.55));
+    assert_eq!(ordinary_future_value, dec!(331));
+    assert_eq!(due_future_value, dec!(364.1));
+    assert_eq!(payment.round_dp(2), dec!(402.11));
+    assert_eq!(
+        TimeValueOfMoney::get_annuity_present_value(dec!(100), dec!(0), 3, AnnuityTiming::Ordinary),
+        Some(dec!(300))
+    );
+}
+
+#[test]
+fn it_gets_perpetuity_present_values() {
+    assert_eq!(
+        TimeValueOfMoney::get_perpetuity_present_value(dec!(100), dec!(5)),
+        Some(dec!(2000))
+    );
+    assert_eq!(
+        TimeValueOfMoney::get_growing_perpetuity_present_value(dec!(100), dec!(8), dec!(3)),
+        Some(dec!(2000))
+    );
+    assert_eq!(
+        TimeValueOfMoney::get_growing_perpetuity_present_value(dec!(100), dec!(3), dec!(3)),
+        None
+    );
+}
+
+#[test]
+fn it_gets_npv_and_irr() {
+    let cash_flows = vec![dec!(-1000), dec!(500), dec!(500), dec!(500)];
+
+    let npv = TimeValueOfMoney::get_npv(dec!(10), &cash_flows).unwrap();
+    let irr = TimeValueOfMoney::get_irr(&cash_flows).unwrap();
+
+    assert_eq!(npv.round_dp(2), dec!(243.43));
+    assert_eq!(irr.round_dp(2), dec!(23.38));
+    assert_eq!(
+        TimeValueOfMoney::get_npv(irr, &cash_flows)
+            .unwrap()
+            .round_dp(6),
+        dec!(0)
+    );
+    assert_eq!(TimeValueOfMoney::get_irr(&[dec!(100), dec!(100)]), None);
+}
+
+#[test]
+fn it_returns_none_instead_of_dividing_by_zero_or_overflowing() {
+    let cash_flows = vec![dec!(-1000), dec!(500), dec!(500), dec!(500)];
+
+    assert_eq!(TimeValueOfMoney::get_npv(dec!(-100), &cash_flows), None);
+    assert_eq!(
+        TimeValueOfMoney::get_present_value(dec!(1000), dec!(-100), dec!(2)),
+        None
+    );
+    assert_eq!(
+        TimeValueOfMoney::get_future_value(dec!(1000), dec!(1000), dec!(100)),
+        None
+    );
+    assert_eq!(
+        TimeValueOfMoney::get_compound_amount(dec!(1000), dec!(12), 12, dec!(1000)),
+        None
+    );
+    assert_eq!(
+        TimeValueOfMoney::get_compound_amount(dec!(1000), dec!(12), 0, dec!(1)),
+        None
+    );
+    assert_eq!(
+        TimeValueOfMoney::get_effective_annual_rate(dec!(12), 0),
+        None
+    );
+    assert_eq!(
+        TimeValueOfMoney::get_continuous_compound_amount(dec!(1000), dec!(100000), dec!(100)),
+        None
+    );
+    assert_eq!(
+        TimeValueOfMoney::get_continuous_compound_amount(Decimal::MAX, dec!(5), dec!(1)),
+        None
+    );
+    assert_eq!(
+        TimeValueOfMoney::get_annuity_payment(dec!(1000), dec!(-100), 3, AnnuityTiming::Ordinary),
+        None
+    );
+}
+
+#[test]
+fn it_gets_xnpv_and_xirr() {
+    let cash_flows: Vec<(DateTime<Utc>, Decimal)> = vec![
+        (
+            generate_utc_date_from_date_string("01-01-2022 00:00"),
+            dec!(-1000),
+        ),
+        (
+            generate_utc_date_from_date_string("01-01-2023 00:00"),
+            dec!(1100),
+        ),
+    ];
+
+    let xirr = TimeValueOfMoney::get_xirr(&cash_flows).unwrap();
+    let xnpv = TimeValueOfMoney::get_xnpv(dec!(10), &cash_flows).unwrap();
+
+    assert_eq!(xirr.round_dp(2), dec!(10));
+    assert_eq!(xnpv.round_dp(2), dec!(0));
+}
+
+#[test]
+fn it_gets_amortization_schedule() {
+    let schedule = TimeValueOfMoney::get_amortization_schedule(dec!(1000), dec!(1), 3).unwrap();
+
+    assert_eq!(
+        schedule,
+        vec![
+            AmortizationPayment {
+                period: 1,
+                payment: dec!(340.02),
+                interest: dec!(10.00),
+                principal: dec!(330.02),
+                balance: dec!(669.98),
+            },
+            AmortizationPayment {
+                period: 2,
+                payment: dec!(340.02),
+                interest: dec!(6.70),
+                principal: dec!(333.32),
+                balance: dec!(336.66),
+            },
+            AmortizationPayment {
+                period: 3,
+                payment: dec!(340.03),
+                interest: dec!(3.37),
+                principal: dec!(336.66),
+                balance: dec!(0),
+            },
+        ]
+    );
+    assert_eq!(
+        TimeValueOfMoney::get_amortization_schedule(dec!(1000), dec!(1), 0),
+        None
+    );
+}